@@ -1,4 +1,5 @@
 use crate::core_impl::info_extractor::impl_item_method_info::ImplItemMethodInfo;
+use crate::core_impl::info_extractor::owned_return::OwnedReturn;
 use crate::error;
 use crate::info_extractor::{inputs, item_impl_info::ItemImplInfo};
 use proc_macro2::TokenStream as TokenStream2;
@@ -154,6 +155,13 @@ impl ImplItemMethodInfo {
                 #(,#trait_generics_const_exprs)*
                 #(,#method_generics_consts)*
             };
+            // the served `Args` borrow from the input, which is kept
+            // until the end of the call
+            let args_lifetime = if self.inputs.has_borrowed_args() {
+                quote!('args,)
+            } else {
+                quote!()
+            };
             let method_arg_idents = quote! {
                 // https://github.com/rust-lang/rust/issues/42868
                 // TODO: if the function has any late-bound lifetime
@@ -206,7 +214,9 @@ impl ImplItemMethodInfo {
                 .map(|a| {
                     let pat = a.arg.pat.as_ref();
                     let s = quote! {args.#pat};
-                    if let Some(fake) = a.fake_arg.as_ref() {
+                    if let Some(borrowed) = a.borrowed_arg.as_ref() {
+                        borrowed.modify_pat(s)
+                    } else if let Some(fake) = a.fake_arg.as_ref() {
                         let m = &fake.modification;
                         m.modify_pat(s)
                     } else {
@@ -246,20 +256,44 @@ impl ImplItemMethodInfo {
             let recv_kind = &self.inputs.receiver_kind;
 
             let (return_ident, return_type, return_value);
-            match &self.ret {
-                syn::ReturnType::Default => {
+            match (&self.owned_return, &self.ret) {
+                // owned methods give back the new state and,
+                // possibly, a value to be returned
+                (Some(owned_return), _) => {
+                    return_ident = quote!(ret);
+                    return_type = quote!(_);
+                    return_value = match owned_return {
+                        OwnedReturn::StateOnly => quote! {
+                            (ret, None)
+                        },
+                        OwnedReturn::Pair(_) | OwnedReturn::Split(_) => quote! {
+                            let (state, value) =
+                                _interface::SplitState::<Self::State>::split_state(ret);
+                            let ret = #trait_method_mod::Return::<
+                                #trait_and_method_arg_idents
+                            >
+                            {
+                                value,
+                                _phantom: Default::default()
+                            };
+                            (state, Some(ret))
+                        },
+                    };
+                }
+                (None, syn::ReturnType::Default) => {
                     //
                     return_ident = quote!(());
                     return_type = quote!(());
                     return_value = quote!(None);
                 }
-                syn::ReturnType::Type(_t, ty) => {
+                (None, syn::ReturnType::Type(_t, ty)) => {
                     //
                     return_ident = quote!(ret);
                     return_type = {
                         if matches!(
                             recv_kind,
-                            inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
+                            inputs::ReceiverKind::StatelessInit
+                                | inputs::ReceiverKind::StatelessMigrate
                         ) {
                             quote!(#ty::State)
                         } else {
@@ -267,27 +301,29 @@ impl ImplItemMethodInfo {
                         }
                     };
 
-                    let return_value_tmp = quote! {
-                        let ret = #trait_method_mod::Return::<
-                            #trait_and_method_arg_idents
-                        >
-                        {
-                            value: ret,
-                            _phantom: Default::default()
-                        };
+                    return_value = if matches!(
+                        recv_kind,
+                        ReceiverKind::StatelessInit | ReceiverKind::StatelessMigrate
+                    ) {
+                        quote! {
+                            let ret = #trait_method_mod::Return::<
+                                #trait_and_method_arg_idents
+                            >
+                            {
+                                value: ret,
+                                _phantom: Default::default()
+                            };
+                            ret.value
+                        }
+                    } else {
+                        // the `Return` may also unwrap a `Result`
+                        quote! {
+                            let ret = #trait_method_mod::Return::<
+                                #trait_and_method_arg_idents
+                            >::from_method_return(ret);
+                            Some(ret)
+                        }
                     };
-                    return_value =
-                        if matches!(recv_kind, ReceiverKind::Owned | ReceiverKind::StatelessInit) {
-                            quote! {
-                                #return_value_tmp
-                                ret.value
-                            }
-                        } else {
-                            quote! {
-                                #return_value_tmp
-                                Some(ret)
-                            }
-                        };
                 }
             };
 
@@ -295,21 +331,21 @@ impl ImplItemMethodInfo {
                 ReceiverKind::RefMut => {
                     quote! {
                         where
-                            OuterType: _near_sdk::borsh::BorshDeserialize + _near_sdk::borsh::BorshSerialize
+                            OuterType: _interface::StateRead + _interface::StateWrite
                     }
                 }
                 ReceiverKind::Ref => quote! {
                     where
-                        OuterType: _near_sdk::borsh::BorshDeserialize
+                        OuterType: _interface::StateRead
                 },
                 ReceiverKind::Owned => quote! {
                     where
-                        OuterType: _near_sdk::borsh::BorshDeserialize + _near_sdk::borsh::BorshSerialize
+                        OuterType: _interface::StateRead + _interface::StateWrite
                 },
                 ReceiverKind::Stateless => quote!(),
-                ReceiverKind::StatelessInit => quote! {
+                ReceiverKind::StatelessInit | ReceiverKind::StatelessMigrate => quote! {
                     where
-                        OuterType: _near_sdk::borsh::BorshSerialize,
+                        OuterType: _interface::StateWrite,
                         Self::State: Into<OuterType>
                 },
             };
@@ -333,14 +369,20 @@ impl ImplItemMethodInfo {
                 let payable = match (&self.inputs.receiver_kind, self.attrs.payable) {
                     // methods can are Call (non-View) and forbids payments
                     (
-                        ReceiverKind::RefMut | ReceiverKind::Owned | ReceiverKind::StatelessInit,
+                        ReceiverKind::RefMut
+                        | ReceiverKind::Owned
+                        | ReceiverKind::StatelessInit
+                        | ReceiverKind::StatelessMigrate,
                         false,
                     ) => quote! {
                         Self::panic_on_deposit();
                     },
                     // methods can are Call (non-View) and allows payments
                     (
-                        ReceiverKind::RefMut | ReceiverKind::Owned | ReceiverKind::StatelessInit,
+                        ReceiverKind::RefMut
+                        | ReceiverKind::Owned
+                        | ReceiverKind::StatelessInit
+                        | ReceiverKind::StatelessMigrate,
                         true,
                     ) => quote!(),
                     // methods that are View cannot verify if there is
@@ -353,7 +395,7 @@ impl ImplItemMethodInfo {
                 };
 
                 // TODO: decide whether all methods should be private by default,
-                // which tends to be safer - currently they are not
+                // which tends to be safer - currently only migrate methods are
                 let private = if self.attrs.private {
                     quote!(Self::panic_on_non_private();)
                 } else {
@@ -363,23 +405,9 @@ impl ImplItemMethodInfo {
                 // TODO: consider different types of args
                 // (like the sdk: regular, callback, etc)
 
-                let args_empty = self.inputs.args.is_empty();
-
-                // let args = #args_expr;
-                let args_expr = if args_empty {
-                    // empty args still requires some blank structure
-                    // that holds typing information
-                    //
-                    // so even if no args are required, that (zero-sized) value is
-                    // still passed around (as required by the trait definitions)
-                    quote! {
-                        Self::Args {
-                            _phantom: Default::default(),
-                        }
-                    }
-                } else {
-                    quote!(Self::deserialize_args_from_input())
-                };
+                // methods without args also read the input,
+                // which may then be empty
+                let args_expr = quote!(Self::deserialize_args_from_input(&input));
 
                 // note: state initialization based on Defaults are completely disallowed.
                 // all states that will be initialized and saved must be explicitly init
@@ -390,16 +418,35 @@ impl ImplItemMethodInfo {
                 // stil not even be stored.
                 let state_load = match recv_kind {
                     ReceiverKind::RefMut => {
-                        quote! {
-                            let mut contract: OuterType = Self::state_read_or_panic::<OuterType>();
-                            let state: &mut Self::State = access(&mut contract);
+                        if self.attrs.write_if_changed {
+                            // the bytes are later compared to check
+                            // whether the state needs to be written
+                            quote! {
+                                let mut contract: OuterType = Self::state_read_or_panic::<OuterType>();
+                                let state: &mut Self::State = access(&mut contract);
+                                let state_bytes: Vec<u8> = Self::state_bytes(state);
+                            }
+                        } else {
+                            quote! {
+                                let mut contract: OuterType = Self::state_read_or_panic::<OuterType>();
+                                let state: &mut Self::State = access(&mut contract);
+                            }
                         }
                     }
                     ReceiverKind::Ref => {
                         if self.attrs.allow_temporary_state {
+                            // only the state itself is created by default,
+                            // so the contract doesn't need to be `Default`
                             quote! {
-                                let mut contract: OuterType = Self::state_read_or_default::<OuterType>();
-                                let mut state: &Self::State = access(&contract);
+                                let contract: Option<OuterType> = Self::state_read::<OuterType>();
+                                let temporary_state: Self::State;
+                                let state: &Self::State = match &contract {
+                                    Some(contract) => access(contract),
+                                    None => {
+                                        temporary_state = Default::default();
+                                        &temporary_state
+                                    }
+                                };
                             }
                         } else {
                             quote! {
@@ -408,12 +455,11 @@ impl ImplItemMethodInfo {
                             }
                         }
                     }
+                    // the state is only moved out of the contract
+                    // when the method is called
                     ReceiverKind::Owned => {
                         quote! {
-                            let mut contract: OuterType = Self::state_read_or_panic();
-                            // TODO:
-                            // hope that the compiler will optmize the clone away
-                            let mut state: Self::State = access(&mut contract).clone();
+                            let contract: OuterType = Self::state_read_or_panic();
                         }
                     }
                     ReceiverKind::Stateless => quote! {
@@ -427,32 +473,53 @@ impl ImplItemMethodInfo {
                         let contract: OuterType;
                         let state: Self::State;
                     ),
+
+                    // the old state must exist, and the new state
+                    // will be initialized from the method's result later on
+                    ReceiverKind::StatelessMigrate => quote!(
+                        let old_state: Self::OldState =
+                            Self::state_read_or_panic::<Self::OldState>();
+                        let contract: OuterType;
+                    ),
                 };
 
-                // let result = method(#method_params);
-                let method_params = match recv_kind {
-                    ReceiverKind::Stateless => quote!(args),
-                    ReceiverKind::StatelessInit => quote!(args),
-                    ReceiverKind::RefMut => quote!(state, args),
-                    ReceiverKind::Ref => quote!(state, args),
-                    ReceiverKind::Owned => quote!(state, args),
+                let method_call = match recv_kind {
+                    ReceiverKind::Stateless => quote!(let result = method(args);),
+                    ReceiverKind::StatelessInit => quote!(let result = method(args);),
+                    ReceiverKind::StatelessMigrate => quote!(let result = method(old_state, args);),
+                    ReceiverKind::RefMut => quote!(let result = method(state, args);),
+                    ReceiverKind::Ref => quote!(let result = method(state, args);),
+                    // the state is moved out of the contract and the new state
+                    // is moved back in, without requiring a clone
+                    ReceiverKind::Owned => quote! {
+                        let (contract, result) =
+                            Self::replace_state_with(contract, access, |state| method(state, args));
+                    },
                 };
 
-                let result_serialize =
-                    if matches!(recv_kind, ReceiverKind::Owned | ReceiverKind::StatelessInit) {
-                        // init and owned are required to return exactly a State
-                        // so they can never have other output values
-                        quote!()
-                    } else {
-                        // other kind of methods can have output normally
-                        quote!(Self::may_serialize_return_as_output(result);)
-                    };
+                let result_serialize = match recv_kind {
+                    // init and migrate are required to return exactly a State
+                    // so they can never have other output values
+                    ReceiverKind::StatelessInit | ReceiverKind::StatelessMigrate => quote!(),
+                    // other kind of methods can have output normally
+                    // (owned methods already had their new State placed)
+                    _ => quote!(Self::may_serialize_return_as_output(result);),
+                };
 
                 let state_write = match recv_kind {
-                    // ref mut self always (over)writes state
+                    // ref mut self (over)writes state, unless it
+                    // was unchanged and that was checked for
                     ReceiverKind::RefMut => {
-                        quote! {
-                            Self::state_write::<OuterType>(&contract);
+                        if self.attrs.write_if_changed {
+                            quote! {
+                                if Self::state_bytes(access(&mut contract)) != state_bytes {
+                                    Self::state_write::<OuterType>(&contract);
+                                }
+                            }
+                        } else {
+                            quote! {
+                                Self::state_write::<OuterType>(&contract);
+                            }
                         }
                     }
 
@@ -462,16 +529,15 @@ impl ImplItemMethodInfo {
                     // owned always overwrites state, but will give compile-error
                     // if returned value is not a state
                     ReceiverKind::Owned => quote! {
-                        *access(&mut contract) = result;
                         Self::state_write::<OuterType>(&contract);
                     },
 
                     // stateless methods never (over)writes state
                     ReceiverKind::Stateless => quote!(),
 
-                    // init always overwrites state, but will give compile-error
-                    // if returned value is not a state
-                    ReceiverKind::StatelessInit => quote! {
+                    // init and migrate always overwrite state, but will give
+                    // compile-error if returned value is not a state
+                    ReceiverKind::StatelessInit | ReceiverKind::StatelessMigrate => quote! {
                         contract = result.into();
                         Self::state_write::<OuterType>(&contract);
                     },
@@ -495,7 +561,7 @@ impl ImplItemMethodInfo {
                     ReceiverKind::Stateless => quote! {
                         method: Self::Method
                     },
-                    ReceiverKind::StatelessInit => quote! {
+                    ReceiverKind::StatelessInit | ReceiverKind::StatelessMigrate => quote! {
                         method: Self::Method
                     },
                 };
@@ -506,6 +572,7 @@ impl ImplItemMethodInfo {
                     ReceiverKind::Owned => quote!(OuterType),
                     ReceiverKind::Stateless => quote!(),
                     ReceiverKind::StatelessInit => quote!(OuterType),
+                    ReceiverKind::StatelessMigrate => quote!(OuterType),
                 };
 
                 quote! {
@@ -519,9 +586,10 @@ impl ImplItemMethodInfo {
                         #init_check
                         #payable
                         #private
+                        let input = Self::input();
                         let args = #args_expr;
                         #state_load
-                        let result = method(#method_params);
+                        #method_call
                         #result_serialize
                         #state_write
                     }
@@ -539,7 +607,7 @@ impl ImplItemMethodInfo {
                     #outer_type_where_clause
                     {
                         use _interface::ServeRefMut;
-                        let method_wrapper = |state: &mut Self::State, mut args: Self::Args| {
+                        let method_wrapper = |state: &mut Self::State, mut args: Self::Args<'_>| {
                             let #return_ident: #return_type = <Self::State as #trait_path>::#original_method_ident::< //
                                 #method_arg_idents
                             > (state, #(#args_pats),*);
@@ -555,7 +623,7 @@ impl ImplItemMethodInfo {
                     #outer_type_where_clause
                     {
                         use _interface::ServeRef;
-                        let method_wrapper = |state: &Self::State, mut args: Self::Args| {
+                        let method_wrapper = |state: &Self::State, mut args: Self::Args<'_>| {
                             let #return_ident: #return_type = <Self::State as #trait_path>::#original_method_ident::< //
                                 #method_arg_idents
                             > (state, #(#args_pats),*);
@@ -571,7 +639,7 @@ impl ImplItemMethodInfo {
                     #outer_type_where_clause
                     {
                         use _interface::ServeOwned;
-                        let method_wrapper = |state: Self::State, mut args: Self::Args| {
+                        let method_wrapper = |state: Self::State, mut args: Self::Args<'_>| {
                             let #return_ident: #return_type = <Self::State as #trait_path>::#original_method_ident::< //
                                 #method_arg_idents
                             > (state, #(#args_pats),*);
@@ -584,7 +652,7 @@ impl ImplItemMethodInfo {
                     fn extern_serve()
                     {
                         use _interface::ServeStateless;
-                        let method_wrapper = |mut args: Self::Args| {
+                        let method_wrapper = |mut args: Self::Args<'_>| {
                             let #return_ident: #return_type = <Self::State as #trait_path>::#original_method_ident::< //
                                 #method_arg_idents
                             > (#(#args_pats),*);
@@ -598,7 +666,7 @@ impl ImplItemMethodInfo {
                     #outer_type_where_clause
                     {
                         use _interface::ServeStatelessInit;
-                        let method_wrapper = |mut args: Self::Args| {
+                        let method_wrapper = |mut args: Self::Args<'_>| {
                             let #return_ident: #return_type = <Self::State as #trait_path>::#original_method_ident::< //
                                 #method_arg_idents
                             > (#(#args_pats),*);
@@ -607,21 +675,42 @@ impl ImplItemMethodInfo {
                         Self::serve::<OuterType>(method_wrapper);
                     }
                 },
+                inputs::ReceiverKind::StatelessMigrate => quote! {
+                    fn extern_serve<OuterType>()
+                    #outer_type_where_clause
+                    {
+                        use _interface::ServeStatelessMigrate;
+                        let method_wrapper = |old_state: Self::OldState, mut args: Self::Args<'_>| {
+                            let #return_ident: #return_type = <Self::State as #trait_path>::#original_method_ident::< //
+                                #method_arg_idents
+                            > (old_state, #(#args_pats),*);
+                            #return_value
+                        };
+                        Self::serve::<OuterType>(method_wrapper);
+                    }
+                },
             };
 
             let interface_serve = if impl_info.attrs.serve {
-                let recv_kind = &self.inputs.receiver_kind;
-                let result_serializer = if matches!(
-                    recv_kind,
-                    inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
-                ) {
+                // the formats are defined together with the `Args` and `Return` structures
+                let result_serializer = quote! {
+                    #trait_method_mod::ReturnFormat,
+                };
+                let empty_args = if self.inputs.args.is_empty() {
+                    // empty args still requires some blank structure
+                    // that holds typing information
+                    //
+                    // so even if no args are required, that (zero-sized) value is
+                    // still passed around (as required by the trait definitions)
                     quote! {
-                        _interface::Borsh,
+                        fn empty_args<'args>() -> Option<Self::Args<'args>> {
+                            Some(Self::Args {
+                                _phantom: Default::default(),
+                            })
+                        }
                     }
                 } else {
-                    quote! {
-                        _interface::Json,
-                    }
+                    quote!()
                 };
                 quote! {
                     #[doc = #doc_generated]
@@ -636,19 +725,22 @@ impl ImplItemMethodInfo {
                         #(#impl_generic_types,)*
                         #(#impl_generic_consts,)*
                         #(#method_generics_consts,)*
+                        _Name: _interface::ExportedName,
                     > _interface::Serve< //
-                        _interface::Json,
+                        #trait_method_mod::ArgsFormat,
                         #result_serializer
                         _Diverger
                     > //
-                    for  #trait_method_mod::serve::Serve<#trait_and_method_arg_idents>
+                    for  #trait_method_mod::serve::ServeAs<#trait_and_method_arg_idents, _Name>
                     #where_clause
                     {
+                        const METHOD_NAME: &'static str = <_Name as _interface::ExportedName>::NAME;
                         type State = #state_ty;
-                        type Args = #trait_method_mod::Args<#trait_and_method_arg_idents>;
+                        type Args<'args> = #trait_method_mod::Args<#args_lifetime #trait_and_method_arg_idents>;
                         type Return = #trait_method_mod::Return< //
                             #trait_and_method_arg_idents
                         >;
+                        #empty_args
                     }
 
                 }
@@ -661,29 +753,38 @@ impl ImplItemMethodInfo {
                 let trait_generic_args = {
                     if matches!(
                         recv_kind,
-                        inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
+                        inputs::ReceiverKind::StatelessInit
+                            | inputs::ReceiverKind::StatelessMigrate
                     ) {
                         quote! {
-                                _interface::Json,
+                                #trait_method_mod::ArgsFormat,
                                 _Diverger
                         }
                     } else {
                         quote! {
-                                _interface::Json,
-                                _interface::Json,
+                                #trait_method_mod::ArgsFormat,
+                                #trait_method_mod::ReturnFormat,
                                 _Diverger
                         }
                     }
                 };
-                let method_type_return = {
-                    if matches!(
-                        recv_kind,
-                        inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
-                    ) {
-                        quote!(Self::State)
-                    } else {
-                        quote!(Option<Self::Return>)
-                    }
+                // only the temporary state requires a default
+                let temporary_state_bound = if self.attrs.allow_temporary_state {
+                    quote!(#state_ty: Default,)
+                } else {
+                    quote!()
+                };
+                // the old state is read from the storage
+                let old_state_type = if let Some(old_state_ty) = &self.old_state_ty {
+                    quote!(type OldState = #old_state_ty;)
+                } else {
+                    quote!()
+                };
+                let method_type_return = match recv_kind {
+                    inputs::ReceiverKind::StatelessInit => quote!(Self::State),
+                    inputs::ReceiverKind::StatelessMigrate => quote!(Self::State),
+                    inputs::ReceiverKind::Owned => quote!((Self::State, Option<Self::Return>)),
+                    _ => quote!(Option<Self::Return>),
                 };
                 quote! {
                     #[doc = #doc_generated]
@@ -700,13 +801,16 @@ impl ImplItemMethodInfo {
                         #(#impl_generic_types,)*
                         #(#impl_generic_consts,)*
                         #(#method_generics_consts,)*
+                        _Name: _interface::ExportedName,
                     > #receiver_kind_trait_name< //
                         #trait_generic_args
                     > //
-                    for  #trait_method_mod::serve::Serve<#trait_and_method_arg_idents>
+                    for  #trait_method_mod::serve::ServeAs<#trait_and_method_arg_idents, _Name>
                     #where_clause
+                    #temporary_state_bound
                     {
-                        type Method = fn(#receiver_kind_state Self::Args) -> #method_type_return;
+                        #old_state_type
+                        type Method = fn(#receiver_kind_state Self::Args<'_>) -> #method_type_return;
 
                         #receiver_kind_extern_serve
 
@@ -717,6 +821,57 @@ impl ImplItemMethodInfo {
                 quote!()
             };
 
+            let interface_method_info = if impl_info.attrs.serve {
+                let trait_name = last_segment.ident.to_string();
+                let receiver_kind = recv_kind.quote_kind();
+                let payable = self.attrs.payable;
+                let private = self.attrs.private;
+                let init = matches!(recv_kind, ReceiverKind::StatelessInit);
+                // the names and types as they are owned by clients
+                let (arg_names, arg_types): (Vec<_>, Vec<_>) = self
+                    .inputs
+                    .args
+                    .iter()
+                    .map(|a| {
+                        let arg = a.owned_arg();
+                        let pat = &arg.pat;
+                        let ty = &arg.ty;
+                        (quote!(#pat).to_string(), super::type_str(&quote!(#ty)))
+                    })
+                    .unzip();
+                quote! {
+                    #[doc = #doc_generated]
+                    #[doc = ""]
+                    #[doc = " This implementation describes the method at compile-time."]
+                    #[doc = ""]
+                    #(#attr_docs)*
+                    impl < //
+                        #(#impl_generic_lifetimes,)*
+                        #(#method_generics_lifetimes,)*
+                        #(#method_generics_types,)*
+                        #(#impl_generic_types,)*
+                        #(#impl_generic_consts,)*
+                        #(#method_generics_consts,)*
+                        _Name: _interface::ExportedName,
+                    > _interface::MethodInfo<_Diverger>
+                    for  #trait_method_mod::serve::ServeAs<#trait_and_method_arg_idents, _Name>
+                    #where_clause
+                    {
+                        const METHOD_NAME: &'static str = <_Name as _interface::ExportedName>::NAME;
+                        const TRAIT_NAME: &'static str = #trait_name;
+                        const RECEIVER_KIND: _interface::ReceiverKind = #receiver_kind;
+                        const PAYABLE: bool = #payable;
+                        const PRIVATE: bool = #private;
+                        const INIT: bool = #init;
+                        const ARG_NAMES: &'static [&'static str] = &[#(#arg_names),*];
+                        const ARG_TYPES: &'static [&'static str] = &[#(#arg_types),*];
+                        const RETURN_TYPE: Option<&'static str> = #trait_method_mod::RETURN_TYPE;
+                    }
+                }
+            } else {
+                quote!()
+            };
+
             let serve_shortcut_type = if impl_info.attrs.serve {
                 quote! {
                     #[doc = #doc_generated]
@@ -736,6 +891,21 @@ impl ImplItemMethodInfo {
                         #(#impl_generic_consts,)*
                         #(#method_generics_consts,)*
                     > = #trait_method_mod::serve::Serve<#trait_and_method_arg_idents>;
+
+                    #[doc = #doc_generated]
+                    #[doc = ""]
+                    #[doc = " The same as [`Serve`], but exported as the `_Name`."]
+                    #[doc = ""]
+                    #(#attr_docs)*
+                    pub type ServeAs<
+                        #(#impl_generic_lifetimes,)*
+                        #(#method_generics_lifetimes,)*
+                        #(#method_generics_types,)*
+                        #(#impl_generic_types,)*
+                        #(#impl_generic_consts,)*
+                        #(#method_generics_consts,)*
+                        _Name,
+                    > = #trait_method_mod::serve::ServeAs<#trait_and_method_arg_idents, _Name>;
                 }
             } else {
                 quote!()
@@ -758,7 +928,7 @@ impl ImplItemMethodInfo {
                     #(#impl_generic_types,)*
                     #(#impl_generic_consts,)*
                     #(#method_generics_consts,)*
-                > = #trait_method_mod::request::Request<#trait_and_method_arg_idents>;
+                > = <#trait_method_mod::serve::Serve<#trait_and_method_arg_idents> as #trait_method_mod::request::StateRequest>::Request;
             };
 
             quote! {
@@ -775,6 +945,8 @@ impl ImplItemMethodInfo {
 
                     #interface_args_serve
 
+                    #interface_method_info
+
                     #serve_shortcut_type
 
                     #request_shortcut_type
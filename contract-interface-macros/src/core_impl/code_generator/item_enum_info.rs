@@ -0,0 +1,113 @@
+use crate::core_impl::info_extractor::item_enum_info::ItemEnumInfo;
+use crate::error;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+impl ItemEnumInfo {
+    /// Generate the typed events and their metadata.
+    pub fn wrapped_module(&self) -> error::Result<TokenStream2> {
+        let internal_interface = crate::crate_name("contract-interface")?;
+
+        let mut original = self.original.clone();
+        original.attrs.clear();
+        let non_contract_attrs = &self.non_contract_attrs;
+        let enum_mod_name = &self.attrs.module_name;
+        let enum_doc_attrs = &self.doc_attrs;
+        let original_enum_ident = &self.original_ident;
+        let standard = &self.attrs.event.standard;
+        let version = &self.attrs.event.version;
+
+        let original_doc_msg = format!(
+            " Each variant is an event of the `{}` standard, version `{}`. For the typed events, see [`{}`].",
+            standard, version, enum_mod_name
+        );
+        let mod_doc_msg = format!(" Generated code based on [`{}`].", original_enum_ident);
+
+        let names = self.events.iter().map(|e| &e.name).collect::<Vec<_>>();
+
+        // a typed event constructor for each variant
+        let constructors = self.events.iter().map(|e| {
+            let fn_ident = syn::Ident::new(&e.name, e.ident.span());
+            let name = &e.name;
+            let doc_msg = format!(
+                " The [`{}::{}`] event, logged as `\"{}\"`.",
+                original_enum_ident, e.ident, name
+            );
+            match &e.data {
+                Some(data) => quote! {
+                    #[doc = #doc_msg]
+                    pub fn #fn_ident(data: &#data) -> _interface::EventLog<'_, #data> {
+                        _interface::EventLog {
+                            standard: STANDARD,
+                            version: VERSION,
+                            event: #name,
+                            data: Some(data),
+                        }
+                    }
+                },
+                None => quote! {
+                    #[doc = #doc_msg]
+                    pub fn #fn_ident() -> _interface::EventLog<'static, ()> {
+                        _interface::EventLog {
+                            standard: STANDARD,
+                            version: VERSION,
+                            event: #name,
+                            data: None,
+                        }
+                    }
+                },
+            }
+        });
+
+        let emit_arms = self.events.iter().map(|e| {
+            let variant = &e.ident;
+            let fn_ident = syn::Ident::new(&e.name, e.ident.span());
+            match &e.data {
+                Some(_) => quote! {
+                    Self::#variant(data) => #enum_mod_name::#fn_ident(data).emit(),
+                },
+                None => quote! {
+                    Self::#variant => #enum_mod_name::#fn_ident().emit(),
+                },
+            }
+        });
+
+        Ok(quote! {
+            #(#enum_doc_attrs)*
+            #[doc = ""]
+            #[doc = #original_doc_msg]
+            #(#non_contract_attrs)*
+            #original
+
+            #[doc = #mod_doc_msg]
+            #[doc = ""]
+            #(#enum_doc_attrs)*
+            pub mod #enum_mod_name {
+                use super::*;
+                use #internal_interface as _interface;
+
+                /// The name of the standard that defines the events.
+                pub const STANDARD: &str = #standard;
+
+                /// The version of the standard.
+                pub const VERSION: &str = #version;
+
+                #(#constructors)*
+            }
+
+            impl #internal_interface::Event for #original_enum_ident {
+                const METADATA: #internal_interface::EventMetadata = #internal_interface::EventMetadata {
+                    standard: #standard,
+                    version: #version,
+                    events: &[#(#names),*],
+                };
+
+                fn emit(&self) {
+                    match self {
+                        #(#emit_arms)*
+                    }
+                }
+            }
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use crate::error;
 use crate::info_extractor::item_impl_info::ItemImplInfo;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 
 impl ItemImplInfo {
     /// Generate the code that wraps
@@ -21,6 +22,14 @@ impl ItemImplInfo {
         let mut macro_method_params = Vec::new();
         let mut macro_methods_direct_state = Vec::new();
         let mut macro_methods_field_state = Vec::new();
+        let mut method_interfaces = Vec::new();
+
+        let trait_name = self
+            .trait_path
+            .as_ref()
+            .and_then(|p| p.segments.last())
+            .map(|s| s.ident.to_string())
+            .unwrap_or_default();
 
         for (original_method_ident, method) in &self.items.methods {
             result.extend(method.method_wrapper(original_method_ident, self)?);
@@ -107,6 +116,7 @@ impl ItemImplInfo {
                 },
                 ReceiverKind::Stateless => quote!(),
                 ReceiverKind::StatelessInit => quote!(),
+                ReceiverKind::StatelessMigrate => quote!(),
             };
 
             let field_state_access = match method.inputs.receiver_kind {
@@ -121,6 +131,7 @@ impl ItemImplInfo {
                 },
                 ReceiverKind::Stateless => quote!(),
                 ReceiverKind::StatelessInit => quote!(),
+                ReceiverKind::StatelessMigrate => quote!(),
             };
 
             let extern_serve_type = match method.inputs.receiver_kind {
@@ -129,17 +140,24 @@ impl ItemImplInfo {
                 ReceiverKind::Owned => quote! ($stored_type),
                 ReceiverKind::Stateless => quote!(),
                 ReceiverKind::StatelessInit => quote! ($stored_type),
+                ReceiverKind::StatelessMigrate => quote! ($stored_type),
             };
 
+            let original_method_name = original_method_ident.to_string();
             let fn_template_direct_state = quote! {
                 // TODO: consider adding arbitrary feature flag
                 // TODO: add #[cfg(target_arch = "wasm32")]
-                #[no_mangle]
+                #[export_name = concat!($($prefix,)? #original_method_name)]
                 pub extern "C" fn #original_method_ident() {
                     use #internal_interface as _interface;
                     use #receiver_kind;
-                    $($impl_mod::)*#original_method_ident::Serve::<
+                    struct _Exported;
+                    impl _interface::ExportedName for _Exported {
+                        const NAME: &'static str = concat!($($prefix,)? #original_method_name);
+                    }
+                    $($impl_mod::)*#original_method_ident::ServeAs::<
                         #generic_idents
+                        _Exported,
                     >::extern_serve::<#extern_serve_type>(
                         #direct_state_access
                     );
@@ -148,18 +166,54 @@ impl ItemImplInfo {
             let fn_template_field_state = quote! {
                 // TODO: consider adding arbitrary feature flag
                 // TODO: add #[cfg(target_arch = "wasm32")]
-                #[no_mangle]
+                #[export_name = concat!($($prefix,)? #original_method_name)]
                 pub extern "C" fn #original_method_ident() {
                     use #internal_interface as _interface;
                     use #receiver_kind;
-                    $($impl_mod::)*#original_method_ident::Serve::<
+                    struct _Exported;
+                    impl _interface::ExportedName for _Exported {
+                        const NAME: &'static str = concat!($($prefix,)? #original_method_name);
+                    }
+                    $($impl_mod::)*#original_method_ident::ServeAs::<
                         #generic_idents
+                        _Exported,
                     >::extern_serve::<#extern_serve_type>(
                         #field_state_access
                     );
                 }
             };
 
+            if let Some(trait_mod) = &self.attrs.trait_mod_path {
+                let method_mod_name = &method.attrs.module_name;
+                let receiver_kind = method.inputs.receiver_kind.quote_kind();
+                let payable = method.attrs.payable;
+                let private = method.attrs.private;
+                method_interfaces.push(quote! {
+                    _interface::MethodInterface {
+                        name: #original_method_name.to_string(),
+                        trait_name: #trait_name,
+                        receiver: #receiver_kind,
+                        view: #receiver_kind.is_view(),
+                        payable: #payable,
+                        private: #private,
+                        args_format: <
+                            #trait_mod::#method_mod_name::ArgsFormat as _interface::FormatName
+                        >::NAME,
+                        return_format: <
+                            #trait_mod::#method_mod_name::ReturnFormat as _interface::FormatName
+                        >::NAME,
+                        args: _interface::ArgInterface::from_names_types(
+                            #trait_mod::#method_mod_name::ARG_NAMES,
+                            #trait_mod::#method_mod_name::ARG_TYPES,
+                            #trait_mod::#method_mod_name::ARG_DEFAULTS,
+                        ),
+                        return_type: #trait_mod::#method_mod_name::RETURN_TYPE,
+                        default_gas: #trait_mod::#method_mod_name::DEFAULT_GAS.map(Into::into),
+                        default_deposit: #trait_mod::#method_mod_name::DEFAULT_DEPOSIT.map(Into::into),
+                    }
+                });
+            }
+
             macro_methods_direct_state.push(fn_template_direct_state);
             macro_methods_field_state.push(fn_template_field_state);
             methods.push(original_method_ident.clone());
@@ -205,7 +259,205 @@ impl ItemImplInfo {
 
         let doc_attrs = &self.doc_attrs;
 
+        // the supertraits are served by their own `extern_*!` macros,
+        // which may also serve their own supertraits
+        let supertrait_macros = self
+            .attrs
+            .supertraits
+            .iter()
+            .map(|s| dollar_crate(&s.extern_macro))
+            .collect::<Vec<_>>();
+        let supertrait_impl_mods = self
+            .attrs
+            .supertraits
+            .iter()
+            .map(|s| dollar_crate(&s.impl_mod))
+            .collect::<Vec<_>>();
+        // the supertraits that are also served by another supertrait
+        // don't serve their own supertraits, which would otherwise be
+        // served twice
+        let supertrait_skips = self
+            .attrs
+            .supertraits
+            .iter()
+            .map(|s| {
+                if s.skip_supertraits {
+                    quote!(, skip_supertraits)
+                } else {
+                    quote!()
+                }
+            })
+            .collect::<Vec<_>>();
+        // the supertraits' implementations have the same generics,
+        // which are forwarded to their macros
+        let supertrait_generics = quote! {
+            #(, <#impl_generic_type_idents> = $#impl_generic_type_idents)*
+            #(, <#impl_generic_const_idents> = $#impl_generic_const_idents)*
+        };
+
+        // a supertrait must be a bound of the trait, which is checked by
+        // requiring it from a `_State` that is only known to implement the trait
+        let assert_trait = match &self.trait_path {
+            Some(trait_path) => {
+                let impl_generic_lifetimes = self.generics.lifetimes.values();
+                let impl_generic_types = self.generics.types.values();
+                let impl_generic_consts = self.generics.consts.values();
+                let where_clause = &self.original.generics.where_clause;
+                let impl_generic_idents = quote! {
+                    #(#impl_generic_type_idents,)*
+                    #(#impl_generic_const_idents,)*
+                };
+                // the errors point to the supertrait that is not a bound
+                let supertrait_asserts = self.attrs.supertraits.iter().map(|s| {
+                    let impl_mod = &s.impl_mod;
+                    quote_spanned! {impl_mod.span()=>
+                        #impl_mod::assert_trait::<#impl_generic_idents _State>();
+                    }
+                });
+                quote! {
+                    /// Requires `_State` to implement the trait of this implementation.
+                    ///
+                    /// Also requires the supertraits set with `#[contract(supertrait(..))]`,
+                    /// which fails to compile if they are not bounds of the trait.
+                    #[doc(hidden)]
+                    pub fn assert_trait<
+                        #(#impl_generic_lifetimes,)*
+                        #(#impl_generic_types,)*
+                        #(#impl_generic_consts,)*
+                        _State: #trait_path,
+                    >()
+                    #where_clause
+                    {
+                        #(#supertrait_asserts)*
+                    }
+                }
+            }
+            None => quote!(),
+        };
+
+        let standards = {
+            let trait_standards = match &self.attrs.trait_mod_path {
+                Some(trait_mod_path) => quote!(#trait_mod_path::STANDARDS.to_vec()),
+                None => quote!(vec![]),
+            };
+            let supertrait_impl_mods = self.attrs.supertraits.iter().map(|s| &s.impl_mod);
+            quote! {
+                /// The standards implemented by this implementation,
+                /// including the ones from it's supertraits.
+                pub fn standards() -> Vec<#internal_interface::Standard> {
+                    #[allow(unused_mut)]
+                    let mut standards = #trait_standards;
+                    // a supertrait may be shared by other supertraits
+                    for standard in std::iter::empty::<#internal_interface::Standard>()
+                        #(.chain(#supertrait_impl_mods::standards()))*
+                    {
+                        if !standards.contains(&standard) {
+                            standards.push(standard);
+                        }
+                    }
+                    standards
+                }
+            }
+        };
+
+        let methods = {
+            let supertrait_impl_mods = self.attrs.supertraits.iter().map(|s| &s.impl_mod);
+            quote! {
+                /// Information about the methods of this implementation,
+                /// including the ones from it's supertraits.
+                ///
+                /// The names are not prefixed, and the methods of a supertrait
+                /// that is shared by other supertraits are listed once.
+                pub fn methods() -> Vec<#internal_interface::MethodInterface> {
+                    use #internal_interface as _interface;
+                    #[allow(unused_mut)]
+                    let mut methods = vec![#(#method_interfaces),*];
+                    for method in std::iter::empty::<_interface::MethodInterface>()
+                        #(.chain(#supertrait_impl_mods::methods()))*
+                    {
+                        if !methods.iter().any(|m| m.name == method.name) {
+                            methods.push(method);
+                        }
+                    }
+                    methods
+                }
+            }
+        };
+
+        let json_schemas = if cfg!(feature = "schemars") {
+            let supertrait_impl_mods = self.attrs.supertraits.iter().map(|s| &s.impl_mod);
+            // the schemas require concrete types, so implementations
+            // that have their own generics are not described
+            let has_generics = !self.generics.lifetimes.is_empty()
+                || !self.generics.types.is_empty()
+                || !self.generics.consts.is_empty();
+            let trait_schema = match (&self.attrs.trait_mod_path, &self.trait_path) {
+                (Some(trait_mod), Some(trait_path)) if !has_generics => {
+                    // the state is placed right after the lifetimes
+                    let (mut lifetimes, mut others) = (vec![], vec![]);
+                    if let Some(syn::PathArguments::AngleBracketed(a)) =
+                        trait_path.segments.last().map(|s| &s.arguments)
+                    {
+                        for a in &a.args {
+                            match a {
+                                syn::GenericArgument::Lifetime(_) => lifetimes.push(a),
+                                _ => others.push(a),
+                            }
+                        }
+                    }
+                    quote! {
+                        schemas.push(#trait_mod::json_schema::<
+                            #(#lifetimes,)*
+                            #self_ty,
+                            #(#others,)*
+                        >());
+                    }
+                }
+                _ => quote!(),
+            };
+            quote! {
+                /// The json schemas of the methods of this implementation,
+                /// including the ones from it's supertraits.
+                ///
+                /// Implementations that have their own generics are not included.
+                pub fn json_schemas() -> Vec<#internal_interface::schemars::schema::RootSchema> {
+                    #[allow(unused_mut)]
+                    let mut schemas = vec![];
+                    #trait_schema
+                    for schema in std::iter::empty::<#internal_interface::schemars::schema::RootSchema>()
+                        #(.chain(#supertrait_impl_mods::json_schemas()))*
+                    {
+                        if !schemas.contains(&schema) {
+                            schemas.push(schema);
+                        }
+                    }
+                    schemas
+                }
+            }
+        } else {
+            quote!()
+        };
+
         let macros = if self.attrs.serve {
+            let generics_pattern = quote! {
+                // #(, <#impl_generic_lifetime_idents> = $#impl_generic_lifetime_idents:path)*
+                #(, <#impl_generic_type_idents> = $#impl_generic_type_idents:path)*
+                #(, <#impl_generic_const_idents> = $#impl_generic_const_idents:expr)*
+                #(, #macro_method_params)*
+            };
+            let field_state_pattern = quote! {
+                stored_type = $stored_type:path
+                , state_access = state.$($state_access:ident).*
+                , impl_mod = $($impl_mod:ident)::*
+                $(, prefix = $prefix:literal)?
+                #generics_pattern
+            };
+            let direct_state_pattern = quote! {
+                stored_type = $stored_type:path
+                , impl_mod = $($impl_mod:ident)::*
+                $(, prefix = $prefix:literal)?
+                #generics_pattern
+            };
             quote! {
                 #[macro_use]
                 mod exported_macro {
@@ -213,30 +465,51 @@ impl ItemImplInfo {
                     #[doc = ""]
                     #[doc = " Generates `extern \"C\"` functions for the methods of this implementation."]
                     #[doc = ""]
+                    #[doc = " An optional `prefix = \"..\"` is prepended to the exported names, so"]
+                    #[doc = " the same implementation can be served more than once, eg. for different"]
+                    #[doc = " fields of the state. Each of those invocations must be placed in"]
+                    #[doc = " a different module."]
+                    #[doc = ""]
+                    #[doc = " The methods of supertraits set with `#[contract(supertrait(..))]`"]
+                    #[doc = " are also served, unless a trailing `, skip_supertraits` is given."]
+                    #[doc = " An implementation that is served more than once for the same prefix,"]
+                    #[doc = " such as a supertrait that is also served directly, fails to compile"]
+                    #[doc = " with it's methods being defined twice."]
+                    #[doc = ""]
                     #(#doc_attrs)*
                     #[macro_export]
                     macro_rules! #struct_macro_name {
-                        (
-                            stored_type = $stored_type:path
-                            , state_access = state.$($state_access:ident).*
-                            , impl_mod = $($impl_mod:ident)::*
-                            // #(, <#impl_generic_lifetime_idents> = $#impl_generic_lifetime_idents:path)*
-                            #(, <#impl_generic_type_idents> = $#impl_generic_type_idents:path)*
-                            #(, <#impl_generic_const_idents> = $#impl_generic_const_idents:expr)*
-                            #(, #macro_method_params)*
-                        ) => {
+                        (#field_state_pattern, skip_supertraits) => {
+                            #(#macro_methods_field_state)*
+                        };
+                        (#field_state_pattern) => {
                             #(#macro_methods_field_state)*
+                            #(
+                                #supertrait_macros!(
+                                    stored_type = $stored_type
+                                    , state_access = state.$($state_access).*
+                                    , impl_mod = #supertrait_impl_mods
+                                    $(, prefix = $prefix)?
+                                    #supertrait_generics
+                                    #supertrait_skips
+                                );
+                            )*
                         };
-                        (
-                            stored_type = $stored_type:path
-                            , impl_mod = $($impl_mod:ident)::*
-                            // #(, <#impl_generic_lifetime_idents> = $#impl_generic_lifetime_idents:path)*
-                            #(, <#impl_generic_type_idents> = $#impl_generic_type_idents:path)*
-                            #(, <#impl_generic_const_idents> = $#impl_generic_const_idents:expr)*
-                            #(, #macro_method_params)*
-                        ) => {
+                        (#direct_state_pattern, skip_supertraits) => {
                             #(#macro_methods_direct_state)*
                         };
+                        (#direct_state_pattern) => {
+                            #(#macro_methods_direct_state)*
+                            #(
+                                #supertrait_macros!(
+                                    stored_type = $stored_type
+                                    , impl_mod = #supertrait_impl_mods
+                                    $(, prefix = $prefix)?
+                                    #supertrait_generics
+                                    #supertrait_skips
+                                );
+                            )*
+                        };
                     }
                 }
             }
@@ -260,9 +533,29 @@ impl ItemImplInfo {
 
                 #macros
 
+                #assert_trait
+
+                #standards
+
+                #methods
+
+                #json_schemas
+
                 #(#result)*
 
             }
         })
     }
 }
+
+/// Replaces a leading `crate` by `$crate`, so the path can be used
+/// from within a `macro_rules` that is called by other crates.
+fn dollar_crate(path: &syn::Path) -> TokenStream2 {
+    match path.segments.first() {
+        Some(first) if path.leading_colon.is_none() && first.ident == "crate" => {
+            let rest = path.segments.iter().skip(1);
+            quote!($crate #(::#rest)*)
+        }
+        _ => quote!(#path),
+    }
+}
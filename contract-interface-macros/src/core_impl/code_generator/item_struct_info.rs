@@ -0,0 +1,201 @@
+use crate::core_impl::info_extractor::item_struct_info::ItemStructInfo;
+use crate::error;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+impl ItemStructInfo {
+    /// Generate the versions of the state.
+    pub fn wrapped_module(&self) -> error::Result<TokenStream2> {
+        let internal_interface = crate::crate_name("contract-interface")?;
+        let near_sdk = crate::crate_name("near-sdk")?;
+
+        let mut original = self.original.clone();
+        original.attrs.clear();
+        let non_contract_attrs = &self.non_contract_attrs;
+        let struct_mod_name = &self.attrs.module_name;
+        let struct_doc_attrs = &self.doc_attrs;
+        let original_struct_ident = &self.original_ident;
+
+        if self.json_schema {
+            return self.json_schema_struct();
+        }
+
+        let (original_doc_msg, mod_items) = if self.lazy_fields.is_empty() {
+            (
+                format!(" For it's stored versions, see [`{}`].", struct_mod_name),
+                self.versions_items(),
+            )
+        } else {
+            (
+                format!(" For it's stored fields, see [`{}`].", struct_mod_name),
+                self.lazy_fields_items(),
+            )
+        };
+        let mod_doc_msg = format!(" Generated code based on [`{}`].", original_struct_ident);
+
+        Ok(quote! {
+            #(#struct_doc_attrs)*
+            #[doc = ""]
+            #[doc = #original_doc_msg]
+            #(#non_contract_attrs)*
+            #original
+
+            #[doc = #mod_doc_msg]
+            #[doc = ""]
+            #(#struct_doc_attrs)*
+            pub mod #struct_mod_name {
+                use super::*;
+                use #internal_interface as _interface;
+                use #near_sdk as _near_sdk;
+                // the borsh derive expects `borsh` to be in scope
+                use _near_sdk::borsh;
+
+                #mod_items
+            }
+        })
+    }
+
+    /// Generate the versions enum and the upgrade between them.
+    fn versions_items(&self) -> TokenStream2 {
+        let original_struct_ident = &self.original_ident;
+        let versions_doc_msg = format!(
+            " All of the stored versions of [`{}`], from the oldest to the latest.",
+            original_struct_ident
+        );
+
+        let variants = self.versions.iter().map(|(v, _)| v).collect::<Vec<_>>();
+        let paths = self.versions.iter().map(|(_, p)| p).collect::<Vec<_>>();
+        let latest_version = (self.versions.len() - 1) as u8;
+
+        // each version is upgraded into the next one, until the latest
+        let upgrades = self.versions.windows(2).map(|w| {
+            let (variant, _) = &w[0];
+            let (next_variant, _) = &w[1];
+            quote! {
+                Versions::#variant(state) => {
+                    Self::upgrade(Versions::#next_variant(From::from(state)))
+                }
+            }
+        });
+        let latest_variant = variants.last().unwrap();
+
+        quote! {
+            #[doc = #versions_doc_msg]
+            #[derive(_near_sdk::borsh::BorshDeserialize, _near_sdk::borsh::BorshSerialize)]
+            pub enum Versions {
+                #(#variants(#paths),)*
+            }
+
+            impl _interface::VersionedState for #original_struct_ident {
+                type Versions = Versions;
+                const LATEST_VERSION: u8 = #latest_version;
+
+                fn upgrade(versions: Versions) -> Self {
+                    match versions {
+                        #(#upgrades)*
+                        Versions::#latest_variant(state) => state,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generate a marker for each field and it's storage key.
+    fn lazy_fields_items(&self) -> TokenStream2 {
+        let original_struct_ident = &self.original_ident;
+        let fields_doc_msg = format!(
+            " Markers for each field of [`{}`], that is stored under it's own key.",
+            original_struct_ident
+        );
+
+        let idents = self
+            .lazy_fields
+            .iter()
+            // named fields always have an ident
+            .map(|f| f.ident.as_ref().unwrap())
+            .collect::<Vec<_>>();
+        let tys = self.lazy_fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+        let field_docs = idents.iter().map(|i| {
+            format!(
+                " Marker for the [`{}::{}`] field.",
+                original_struct_ident, i
+            )
+        });
+        let keys = idents.iter().map(|i| {
+            let key = format!("STATE.{}", i);
+            syn::LitByteStr::new(key.as_bytes(), i.span())
+        });
+
+        quote! {
+            #[doc = #fields_doc_msg]
+            pub mod fields {
+                #(
+                    #[doc = #field_docs]
+                    #[allow(non_camel_case_types)]
+                    pub struct #idents;
+                )*
+            }
+
+            #(
+                impl _interface::StateField for fields::#idents {
+                    type Value = #tys;
+                    const KEY: &'static [u8] = #keys;
+                }
+            )*
+
+            impl _interface::LazyFields for #original_struct_ident {
+                fn read_fields() -> Option<Self> {
+                    Some(Self {
+                        #(#idents: _interface::lazy::field_read::<fields::#idents>()?,)*
+                    })
+                }
+
+                fn write_fields(&self) {
+                    #(_interface::lazy::field_write::<fields::#idents>(&self.#idents);)*
+                }
+            }
+        }
+    }
+
+    /// Generate the struct itself, which derives `JsonSchema` with
+    /// the `schemars` feature.
+    ///
+    /// As with the `Args` of methods, the types from `near-sdk` are
+    /// described by the ones from `contract_interface::schema`.
+    fn json_schema_struct(&self) -> error::Result<TokenStream2> {
+        let internal_interface = crate::crate_name("contract-interface")?;
+        let near_sdk = crate::crate_name("near-sdk")?;
+
+        let mut original = self.original.clone();
+        original.attrs.clear();
+        let non_contract_attrs = &self.non_contract_attrs;
+        let struct_doc_attrs = &self.doc_attrs;
+
+        let schema_derive = if cfg!(feature = "schemars") {
+            for field in original.fields.iter_mut() {
+                if let Some(schema_ty) =
+                    super::schema_type(&field.ty, &internal_interface, &near_sdk)
+                {
+                    let schema_ty_str = quote!(#schema_ty).to_string();
+                    field
+                        .attrs
+                        .push(syn::parse_quote!(#[schemars(with = #schema_ty_str)]));
+                }
+            }
+            let schemars_crate = format!("{}::schemars", internal_interface);
+            quote! {
+                #[derive(#internal_interface::schemars::JsonSchema)]
+                #[schemars(crate = #schemars_crate)]
+            }
+        } else {
+            quote!()
+        };
+
+        Ok(quote! {
+            #(#struct_doc_attrs)*
+            #(#non_contract_attrs)*
+            #schema_derive
+            #original
+        })
+    }
+}
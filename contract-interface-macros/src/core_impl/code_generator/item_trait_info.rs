@@ -6,6 +6,7 @@ use quote::quote;
 impl ItemTraitInfo {
     /// Generate code that wrapps external calls.
     pub fn wrapped_module(&self) -> error::Result<TokenStream2> {
+        let internal_interface = crate::crate_name("contract-interface")?;
         let mut result = TokenStream2::new();
         let mut original = self.original.clone();
         original.attrs.clear();
@@ -21,6 +22,38 @@ impl ItemTraitInfo {
 
         let original_doc_msg = format!(" For usage as a contract, see [`{}`].", trait_mod_name);
         let mod_doc_msg = format!(" Generated code based on [`{}`].", original_trait_ident);
+        let standards_doc_msg = format!(
+            " The standards implemented by [`{}`], not including it's supertraits.",
+            original_trait_ident
+        );
+        let info_doc_msg = format!(" Describes [`{}`] at compile-time.", original_trait_ident);
+        let trait_name = original_trait_ident.to_string();
+        let method_names = self
+            .items
+            .methods
+            .keys()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>();
+        let json_schema = if cfg!(feature = "schemars") {
+            self.json_schema_fn()
+        } else {
+            quote!()
+        };
+        let standards = self.attrs.standard.iter().map(|(standard, version)| {
+            quote! {
+                #internal_interface::Standard {
+                    standard: #standard,
+                    version: #version,
+                }
+            }
+        });
+
+        let client = self.client()?;
+        let client_alias = quote::format_ident!("{}Client", original_trait_ident);
+        let client_alias_doc_msg = format!(
+            " A client for making requests into a contract that serves [`{}`].",
+            original_trait_ident
+        );
 
         Ok(quote! {
             #(#trait_doc_attrs)*
@@ -29,14 +62,244 @@ impl ItemTraitInfo {
             #(#non_contract_attrs)*
             #original
 
+            #[doc = #client_alias_doc_msg]
+            pub use #trait_mod_name::Client as #client_alias;
+
             #[doc = #mod_doc_msg]
             #[doc = ""]
             #(#trait_doc_attrs)*
             pub mod #trait_mod_name {
                 use super::*;
+                #[allow(unused_imports)]
+                use #internal_interface as _interface;
+
+                #[doc = #standards_doc_msg]
+                pub const STANDARDS: &[#internal_interface::Standard] = &[#(#standards),*];
+
+                #[doc = #info_doc_msg]
+                pub struct Info;
+
+                impl #internal_interface::TraitInfo for Info {
+                    const TRAIT_NAME: &'static str = #trait_name;
+                    const METHODS: &'static [&'static str] = &[#(#method_names),*];
+                }
+
+                #json_schema
+
+                #client
 
                 #result
             }
         })
     }
+
+    /// Whether the trait mentions `Self` in it's generics, not counting
+    /// bounds directly on `Self`, in which case all of the client items
+    /// must be generic over `_State`.
+    pub(crate) fn generics_mention_state(&self) -> bool {
+        let types = self.generics.types.values();
+        let type_bounds = self.generics.type_bounds.values().filter(|pt| {
+            let ty = &pt.bounded_ty;
+            quote!(#ty).to_string() != "_State"
+        });
+        crate::replace_ident::mentions_state(&quote! {
+            #(#types)*
+            #(#type_bounds)*
+        })
+    }
+
+    /// Whether the `Client` must be generic over `_State`, which
+    /// is the case when the trait mentions `Self` in it's generics or
+    /// when any of it's methods is not [`self_free`].
+    ///
+    /// [`self_free`]: crate::core_impl::info_extractor::trait_item_method_info::TraitItemMethodInfo::self_free
+    pub(crate) fn client_needs_state(&self) -> bool {
+        self.generics_mention_state() || self.items.methods.values().any(|m| !m.self_free)
+    }
+
+    /// Generates the `Client` structure, which has a method for each
+    /// of the trait's methods.
+    fn client(&self) -> error::Result<TokenStream2> {
+        let near_sdk = crate::crate_name("near-sdk")?;
+        let with_state = self.client_needs_state();
+        let lifetimes = self.generics.lifetimes.values().collect::<Vec<_>>();
+        let lifetime_idents = self.generics.lifetimes.keys().collect::<Vec<_>>();
+        let types = self.generics.types.values().collect::<Vec<_>>();
+        let type_idents = self.generics.types.keys().collect::<Vec<_>>();
+        let consts = self.generics.consts.values().collect::<Vec<_>>();
+        let const_idents = self.generics.consts.keys().collect::<Vec<_>>();
+        let (state, state_phantom) = if with_state {
+            (quote!(_State,), quote!(std::marker::PhantomData<_State>,))
+        } else {
+            (quote!(), quote!())
+        };
+        let methods = self
+            .items
+            .methods
+            .iter()
+            .map(|(ident, method)| method.client_method(ident, self));
+
+        let trait_ident = &self.original_ident;
+        let doc_msg = format!(
+            " A client for making requests into a contract that serves [`{}`].",
+            trait_ident
+        );
+        let doc_state_msg = if with_state {
+            " As the trait mentions `Self`, the server's `_State` must be informed."
+        } else {
+            ""
+        };
+        Ok(quote! {
+            #[doc = #doc_msg]
+            #[doc = ""]
+            #[doc = " Each method sets the arguments of a request, which then still needs"]
+            #[doc = " the amount of `Near` to be sent and the `Gas` to be attached."]
+            #[doc = ""]
+            #[doc = #doc_state_msg]
+            pub struct Client<
+                #(#lifetimes,)*
+                #state
+                #(#types,)*
+                #(#consts,)*
+            > {
+                contract_being_called: #near_sdk::AccountId,
+                prefix: String,
+                _phantom: (
+                    #(std::marker::PhantomData<&#lifetime_idents ()>,)*
+                    #state_phantom
+                    #(std::marker::PhantomData<#type_idents>,)*
+                ),
+            }
+
+            impl<
+                #(#lifetimes,)*
+                #state
+                #(#types,)*
+                #(#consts,)*
+            > Client<
+                #(#lifetime_idents,)*
+                #state
+                #(#type_idents,)*
+                #(#const_idents,)*
+            > {
+                /// Sets the contract being called.
+                pub fn contract(contract_being_called: #near_sdk::AccountId) -> Self {
+                    Self::contract_with_prefixed_methods(contract_being_called, "")
+                }
+
+                /// Sets the contract being called, where the methods were
+                /// exported with a `prefix`.
+                pub fn contract_with_prefixed_methods(
+                    contract_being_called: #near_sdk::AccountId,
+                    prefix: &str,
+                ) -> Self {
+                    Self {
+                        contract_being_called,
+                        prefix: prefix.to_string(),
+                        _phantom: Default::default(),
+                    }
+                }
+
+                /// The contract being called.
+                pub fn contract_being_called(&self) -> &#near_sdk::AccountId {
+                    &self.contract_being_called
+                }
+
+                /// Prefixed to the names of the methods being called.
+                pub fn prefix(&self) -> &str {
+                    &self.prefix
+                }
+
+                #(#methods)*
+            }
+        })
+    }
+
+    /// Generates the `json_schema()` function, which combines the
+    /// schemas of the `Args` and json `Return` of each method.
+    ///
+    /// Methods that have their own generics, or that don't take json
+    /// args, are not included.
+    fn json_schema_fn(&self) -> TokenStream2 {
+        use crate::core_impl::info_extractor::{format_attr::FormatAttr, inputs::ReceiverKind};
+
+        let lifetimes = self.generics.lifetimes.values().collect::<Vec<_>>();
+        let lifetime_idents = self.generics.lifetimes.keys().collect::<Vec<_>>();
+        let types = self.generics.types.values().collect::<Vec<_>>();
+        let type_idents = self.generics.types.keys().collect::<Vec<_>>();
+        let consts = self.generics.consts.values().collect::<Vec<_>>();
+        let const_idents = self.generics.consts.keys().collect::<Vec<_>>();
+        let lifetime_where_clauses = self.generics.lifetime_bounds.values().collect::<Vec<_>>();
+        let type_where_clauses = self.generics.type_bounds.values().collect::<Vec<_>>();
+
+        let trait_ident = &self.original_ident;
+        let trait_name = trait_ident.to_string();
+        let generics_idents = quote! {
+            #(#lifetime_idents,)*
+            _State,
+            #(#type_idents,)*
+            #(#const_idents,)*
+        };
+
+        let mut bounds = vec![];
+        let mut methods = vec![];
+        for (original_method_ident, method) in &self.items.methods {
+            if !method.generics.lifetimes.is_empty()
+                || !method.generics.types.is_empty()
+                || !method.generics.consts.is_empty()
+                || method.attrs.args_format != FormatAttr::Json
+            {
+                continue;
+            }
+            let method_mod_name = &method.attrs.method_mod_name;
+            let method_name = original_method_ident.to_string();
+            // borrowed args are described as if they borrow from a
+            // static input
+            let args_lifetime = if method.inputs.has_borrowed_args() {
+                quote!('static,)
+            } else {
+                quote!()
+            };
+            let args = quote!(#method_mod_name::Args<#args_lifetime #generics_idents>);
+            bounds.push(quote!(#args: _interface::schemars::JsonSchema));
+            let json_return = matches!(method.attrs.return_format, FormatAttr::Json)
+                && !matches!(
+                    method.inputs.receiver_kind,
+                    ReceiverKind::StatelessInit | ReceiverKind::StatelessMigrate
+                );
+            if json_return {
+                let ret = quote!(#method_mod_name::Return<#generics_idents>);
+                bounds.push(quote!(#ret: _interface::schemars::JsonSchema));
+                methods.push(quote!(.method_with_return::<#args, #ret>(#method_name)));
+            } else {
+                methods.push(quote!(.method::<#args>(#method_name)));
+            }
+        }
+
+        let doc_msg = format!(
+            " The json schemas of the methods of [`{}`], for a given `_State`.",
+            trait_ident
+        );
+        quote! {
+            #[doc = #doc_msg]
+            #[doc = ""]
+            #[doc = " Methods that have their own generics, or that don't take json args, are not included."]
+            pub fn json_schema<
+                #(#lifetimes,)*
+                _State,
+                #(#types,)*
+                #(#consts,)*
+            >() -> _interface::schemars::schema::RootSchema
+            where
+                _State: #trait_ident<#(#lifetime_idents,)* #(#type_idents,)* #(#const_idents,)*>,
+                #(#lifetime_where_clauses,)*
+                #(#type_where_clauses,)*
+                #(#bounds,)*
+            {
+                _interface::schema::TraitSchema::new(#trait_name)
+                    #(#methods)*
+                    .into_root_schema()
+            }
+        }
+    }
 }
@@ -1,4 +1,290 @@
 pub(crate) mod impl_item_method_info;
+pub(crate) mod item_enum_info;
 pub(crate) mod item_impl_info;
+pub(crate) mod item_struct_info;
 pub(crate) mod item_trait_info;
 pub(crate) mod trait_item_method_info;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+/// Writes a type as it's usually written in the source code,
+/// eg. `Option<String>` instead of `Option < String >`.
+pub(crate) fn type_str(ty: &TokenStream2) -> String {
+    let mut s = String::new();
+    write_type_tokens(&mut s, ty.clone());
+    s
+}
+
+/// Writes the tokens of a type, only separating them where a space
+/// is usually written, eg. between words, after `,` and `;`, and
+/// around `->`, `+` and `=`.
+fn write_type_tokens(s: &mut String, tokens: TokenStream2) {
+    use proc_macro2::{Delimiter, Spacing, TokenTree};
+
+    // whether the previous token was a word (an ident or a literal),
+    // or a closing `>`, which are separated from a following word
+    let mut after_word = false;
+    // whether the next token, if any, is separated from the previous one
+    let mut space = false;
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                if space || after_word {
+                    s.push(' ');
+                }
+                s.push_str(&token.to_string());
+                after_word = true;
+                space = false;
+            }
+            TokenTree::Group(g) => {
+                if space {
+                    s.push(' ');
+                }
+                let (open, close) = match g.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                s.push_str(open);
+                write_type_tokens(s, g.stream());
+                s.push_str(close);
+                after_word = false;
+                space = false;
+            }
+            TokenTree::Punct(p) => {
+                let c = p.as_char();
+                let joint = p.spacing() == Spacing::Joint;
+                after_word = false;
+                match c {
+                    ',' | ';' => {
+                        s.push(c);
+                        space = true;
+                    }
+                    '-' if joint => {
+                        // `->`
+                        tokens.next();
+                        s.push_str(" ->");
+                        space = true;
+                    }
+                    '+' | '=' => {
+                        s.push(' ');
+                        s.push(c);
+                        space = true;
+                    }
+                    ':' if joint => {
+                        // `::`
+                        tokens.next();
+                        if space {
+                            s.push(' ');
+                        }
+                        s.push_str("::");
+                        space = false;
+                    }
+                    ':' => {
+                        s.push(c);
+                        space = true;
+                    }
+                    _ => {
+                        if space {
+                            s.push(' ');
+                        }
+                        s.push(c);
+                        after_word = c == '>';
+                        space = false;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The types from `near-sdk` that have a replacement in
+/// `contract_interface::schema`, by their paths within `near-sdk`.
+const SCHEMA_REPLACEMENTS: &[&[&str]] = &[
+    &["AccountId"],
+    &["json_types", "U128"],
+    &["json_types", "U64"],
+    &["json_types", "I128"],
+    &["json_types", "I64"],
+    &["Gas"],
+    &["json_types", "Base64VecU8"],
+    &["PublicKey"],
+];
+
+/// Whether `path` is the `near-sdk` item at `item_path`, written either in
+/// full or as the suffix of it that was imported, eg. `near_sdk::json_types::U128`,
+/// `json_types::U128` or `U128`.
+fn is_near_sdk_path(path: &syn::Path, item_path: &[&str], near_sdk: &syn::Ident) -> bool {
+    let near_sdk = near_sdk.to_string();
+    let full = std::iter::once(near_sdk.as_str())
+        .chain(item_path.iter().copied())
+        .collect::<Vec<_>>();
+    let segments = path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>();
+    if segments.is_empty() || segments.len() > full.len() {
+        return false;
+    }
+    // a leading `::` requires the crate to be written
+    if path.leading_colon.is_some() && segments.len() != full.len() {
+        return false;
+    }
+    segments
+        .iter()
+        .zip(&full[full.len() - segments.len()..])
+        .all(|(segment, expected)| segment == expected)
+}
+
+/// Replaces the types from `near-sdk`, which don't implement
+/// `JsonSchema`, by the ones from `contract_interface::schema`,
+/// and `PromiseOrValue<T>` by `T`.
+///
+/// `interface` and `near_sdk` are how `contract_interface` and `near-sdk`
+/// are named. Types from other crates that have the same names are kept.  
+/// Returns `None` if nothing was replaced.
+pub(crate) fn schema_type(
+    ty: &syn::Type,
+    interface: &syn::Ident,
+    near_sdk: &syn::Ident,
+) -> Option<syn::Type> {
+    use syn::fold::Fold;
+
+    struct Replacer<'i> {
+        interface: &'i syn::Ident,
+        near_sdk: &'i syn::Ident,
+        replaced: bool,
+    }
+
+    impl Fold for Replacer<'_> {
+        fn fold_type(&mut self, ty: syn::Type) -> syn::Type {
+            if let syn::Type::Path(type_path) = &ty {
+                let path = &type_path.path;
+                if let (None, Some(last)) = (&type_path.qself, path.segments.last()) {
+                    let is_replaced = SCHEMA_REPLACEMENTS
+                        .iter()
+                        .any(|item_path| is_near_sdk_path(path, item_path, self.near_sdk));
+                    if is_replaced {
+                        self.replaced = true;
+                        let ident = &last.ident;
+                        let interface = self.interface;
+                        return syn::parse_quote!(#interface::schema::#ident);
+                    }
+                    if is_near_sdk_path(path, &["PromiseOrValue"], self.near_sdk) {
+                        if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+                            if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                                self.replaced = true;
+                                return self.fold_type(inner.clone());
+                            }
+                        }
+                    }
+                }
+            }
+            syn::fold::fold_type(self, ty)
+        }
+    }
+
+    let mut replacer = Replacer {
+        interface,
+        near_sdk,
+        replaced: false,
+    };
+    let ty = replacer.fold_type(ty.clone());
+    if replacer.replaced {
+        Some(ty)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::{format_ident, quote};
+
+    fn schema_type(ty: syn::Type) -> Option<String> {
+        let interface = format_ident!("ci");
+        let near_sdk = format_ident!("near_sdk");
+        super::schema_type(&ty, &interface, &near_sdk).map(|ty| quote!(#ty).to_string())
+    }
+
+    #[test]
+    fn test_schema_type_paths() {
+        let u128 = Some(quote!(ci::schema::U128).to_string());
+        assert_eq!(schema_type(syn::parse_quote!(U128)), u128);
+        assert_eq!(schema_type(syn::parse_quote!(json_types::U128)), u128);
+        assert_eq!(
+            schema_type(syn::parse_quote!(near_sdk::json_types::U128)),
+            u128
+        );
+        assert_eq!(
+            schema_type(syn::parse_quote!(::near_sdk::json_types::U128)),
+            u128
+        );
+        assert_eq!(
+            schema_type(syn::parse_quote!(Vec<near_sdk::AccountId>)),
+            Some(quote!(Vec<ci::schema::AccountId>).to_string())
+        );
+        assert_eq!(
+            schema_type(syn::parse_quote!(near_sdk::PromiseOrValue<U128>)),
+            u128
+        );
+
+        // types with the same name from elsewhere are kept
+        assert_eq!(schema_type(syn::parse_quote!(my_types::U128)), None);
+        assert_eq!(schema_type(syn::parse_quote!(near_sdk::U128)), None);
+        assert_eq!(schema_type(syn::parse_quote!(::json_types::U128)), None);
+        assert_eq!(schema_type(syn::parse_quote!(crate::AccountId)), None);
+        assert_eq!(schema_type(syn::parse_quote!(my::PromiseOrValue<u8>)), None);
+    }
+
+    #[test]
+    fn test_type_str() {
+        let type_str = |ty: syn::Type| super::type_str(&quote!(#ty));
+        assert_eq!(type_str(syn::parse_quote!(u8)), "u8");
+        assert_eq!(type_str(syn::parse_quote!(())), "()");
+        assert_eq!(type_str(syn::parse_quote!([u8; 32])), "[u8; 32]");
+        assert_eq!(type_str(syn::parse_quote!([u8])), "[u8]");
+        assert_eq!(type_str(syn::parse_quote!((u8,))), "(u8,)");
+        assert_eq!(
+            type_str(syn::parse_quote!((u8, String, [u8; 4]))),
+            "(u8, String, [u8; 4])"
+        );
+        assert_eq!(type_str(syn::parse_quote!(&str)), "&str");
+        assert_eq!(
+            type_str(syn::parse_quote!(&'a mut Vec<u8>)),
+            "&'a mut Vec<u8>"
+        );
+        assert_eq!(type_str(syn::parse_quote!(Cow<'a, str>)), "Cow<'a, str>");
+        assert_eq!(
+            type_str(syn::parse_quote!(std::collections::HashMap<String, Vec<Option<u8>>>)),
+            "std::collections::HashMap<String, Vec<Option<u8>>>"
+        );
+        assert_eq!(
+            type_str(syn::parse_quote!(::near_sdk::json_types::U128)),
+            "::near_sdk::json_types::U128"
+        );
+        assert_eq!(
+            type_str(syn::parse_quote!(<T as Iterator>::Item)),
+            "<T as Iterator>::Item"
+        );
+        assert_eq!(
+            type_str(syn::parse_quote!(Box<dyn Fn(u8, &str) -> Option<u8>>)),
+            "Box<dyn Fn(u8, &str) -> Option<u8>>"
+        );
+        assert_eq!(
+            type_str(syn::parse_quote!(
+                Box<dyn Iterator<Item = u8> + Send + 'static>
+            )),
+            "Box<dyn Iterator<Item = u8> + Send + 'static>"
+        );
+        assert_eq!(
+            type_str(syn::parse_quote!(for<'a> fn(&'a u8) -> &'a u8)),
+            "for<'a> fn(&'a u8) -> &'a u8"
+        );
+        assert_eq!(type_str(syn::parse_quote!(*const u8)), "*const u8");
+    }
+}
@@ -1,5 +1,6 @@
 use crate::core_impl::info_extractor::{
-    item_trait_info::ItemTraitInfo, trait_item_method_info::TraitItemMethodInfo,
+    format_attr::FormatAttr, item_trait_info::ItemTraitInfo, owned_return::OwnedReturn,
+    trait_item_method_info::TraitItemMethodInfo,
 };
 use crate::error;
 use proc_macro2::TokenStream as TokenStream2;
@@ -13,6 +14,7 @@ impl TraitItemMethodInfo {
         trait_info: &ItemTraitInfo,
     ) -> error::Result<TokenStream2> {
         let method_mod_name = &self.attrs.method_mod_name;
+        let original_method_ident = original_method_name;
         let original_method_name = format!("{}", original_method_name);
         let attr_docs = &self.doc_attrs;
 
@@ -57,72 +59,43 @@ impl TraitItemMethodInfo {
                 (arg, quote! { #( # [ #forwarded_attr ] )* })
             })
             .unzip();
-        let fake_args = outer_args
+        let fake_args = self.fake_args();
+        let args_fields = self.args_fields();
+        let args_lifetime = self.args_lifetime();
+        // `&str` and `&[u8]` are implicitly borrowed by serde
+        let args_borrow_attrs = self
+            .inputs
+            .args
             .iter()
-            .map(|a| {
-                let mut arg = if let Some(ref fake) = a.fake_arg {
-                    fake.fake_arg.clone()
-                } else {
-                    a.arg.clone()
-                };
-                arg.attrs.clear();
-                arg
+            .map(|a| match &a.borrowed_arg {
+                Some(borrowed) if borrowed.serde_borrow => quote!(#[serde(borrow)]),
+                _ => quote!(),
             })
             .collect::<Vec<_>>();
 
-        let self_lifetime_bounds = &trait_info.self_lifetime_bounds;
-        let self_lifetime_bounds_q = if self_lifetime_bounds.is_empty() {
-            quote! {}
-        } else {
-            quote! {_State: #(#self_lifetime_bounds )+*,}
-        };
-
-        let implicit_self_trait_bound = {
-            let trait_name = &trait_info.original_ident;
-            if !args_trait_lifetime_idents.is_empty()
-                || !args_trait_generic_type_idents.is_empty()
-                || !args_trait_generic_const_idents.is_empty()
-            {
-                quote! {
-                    _State: #trait_name < //
-                      #(#args_trait_lifetime_idents,)*
-                      #(#args_trait_generic_type_idents,)*
-                      #(#args_trait_generic_const_idents,)*
-                    >,
+        // missing args are made by the functions in the `defaults` mod
+        let args_default_attrs = self
+            .inputs
+            .args
+            .iter()
+            .map(|a| match &a.attr.default {
+                Some(_) => {
+                    let pat = &a.arg.pat;
+                    let path = format!("defaults::{}", quote!(#pat));
+                    quote!(#[serde(default = #path)])
                 }
-            } else {
-                quote! {_State: #trait_name ,}
-            }
-        };
-
-        let self_trait_bounds = &trait_info.self_trait_bounds;
-        let self_trait_bounds_q = if self_trait_bounds.is_empty() {
-            quote! {}
+                None => quote!(),
+            })
+            .collect::<Vec<_>>();
+        let args_strict = if (trait_info.attrs.strict || self.attrs.strict)
+            && self.attrs.args_format == FormatAttr::Json
+        {
+            quote!(#[serde(deny_unknown_fields)])
         } else {
-            quote! {_State: #(#self_trait_bounds )+*,}
+            quote!()
         };
 
-        let trait_lifetime_where_clauses = trait_info
-            .generics
-            .lifetime_bounds
-            .values()
-            .collect::<Vec<_>>();
-        let trait_type_where_clauses = trait_info.generics.type_bounds.values().collect::<Vec<_>>();
-
-        let method_lifetime_where_clauses =
-            self.generics.lifetime_bounds.values().collect::<Vec<_>>();
-        let method_type_where_clauses = self.generics.type_bounds.values().collect::<Vec<_>>();
-
-        let where_clause = quote! {
-            where
-                #self_lifetime_bounds_q
-                #self_trait_bounds_q
-                #implicit_self_trait_bound
-                #(#trait_lifetime_where_clauses,)*
-                #(#method_lifetime_where_clauses,)*
-                #(#method_type_where_clauses,)*
-                #(#trait_type_where_clauses,)*
-        };
+        let where_clause = self.where_clause(trait_info, true);
 
         let near_sdk = crate::crate_name("near-sdk")?;
         let near_sdk_str = crate::crate_name_str("near-sdk")?;
@@ -162,9 +135,20 @@ impl TraitItemMethodInfo {
         let mod_doc_str = format!(" Generated code based on {}.  ", &method_link_str);
         let builder_doc_str = format!(" Builder that can be used by a client contract for making a request into a server contract's {} method.", &method_link_str);
 
-        let return_type = match &self.ret {
-            syn::ReturnType::Default => quote! {()},
-            syn::ReturnType::Type(_t, ty) => quote! {#ty},
+        let return_type = match (&self.owned_return, &self.ret) {
+            // owned methods only return what is not the state
+            (Some(OwnedReturn::StateOnly), _) => quote! {()},
+            (Some(OwnedReturn::Pair(ty)), _) => quote! {#ty},
+            (Some(OwnedReturn::Split(ty)), _) => {
+                quote! {<#ty as _interface::SplitState<_State>>::Value}
+            }
+            (None, syn::ReturnType::Default) => quote! {()},
+            // only the `Ok` value is returned
+            (None, syn::ReturnType::Type(_t, _ty)) if self.result_types.is_some() => {
+                let (ok, _err) = self.result_types.as_ref().unwrap();
+                quote! {#ok}
+            }
+            (None, syn::ReturnType::Type(_t, ty)) => quote! {#ty},
         };
 
         let receiver_kind = &self.inputs.receiver_kind;
@@ -179,54 +163,298 @@ impl TraitItemMethodInfo {
             .map(|a| a.arg.pat.as_ref())
             .collect::<Vec<_>>();
 
-        let return_serializer = {
-            use crate::core_impl::info_extractor::inputs;
-            let recv_kind = &self.inputs.receiver_kind;
-            if matches!(
-                recv_kind,
-                inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
-            ) {
+        let return_format = &self.attrs.return_format;
+        let return_format_marker = return_format.quote_marker();
+        let return_format_doc_str = format!(
+            " The serialization format of the [`Return`] given by {}",
+            &method_link_dot_str
+        );
+        // the value's type is a projection on `_State`, so the bound that
+        // serde would infer (`_State: Serialize`) must be replaced
+        let return_value_bound = match (&self.owned_return, return_format) {
+            (Some(OwnedReturn::Split(_)), FormatAttr::Json) => {
+                let bound = format!("{}: _near_sdk::serde::Serialize", return_type);
                 quote! {
-                    #[derive(_near_sdk::borsh::BorshSerialize)]
+                    #[serde(bound(serialize = #bound))]
                 }
-            } else {
+            }
+            _ => quote!(),
+        };
+        let (return_serializer_use, return_serializer, return_serializer_skip) = match return_format
+        {
+            FormatAttr::Borsh => (
+                // the borsh derive expects `borsh` to be in scope
+                quote! {
+                    use _near_sdk::borsh;
+                },
+                quote! {
+                    #[derive(_near_sdk::borsh::BorshSerialize)]
+                },
+                quote! {
+                    #[borsh_skip]
+                },
+            ),
+            FormatAttr::Json => (
+                quote!(),
                 quote! {
                     #[derive(_near_sdk::serde::Serialize)]
                     #[serde(crate = "_near_sdk::serde")]
                     #[serde(transparent)]
+                },
+                quote! {
+                    #[serde(skip)]
+                },
+            ),
+            // the bytes are given by the value itself, see `return_raw`
+            FormatAttr::Raw => (quote!(), quote!(), quote!()),
+            FormatAttr::JsonPositional => unreachable!("rejected by the info extractor"),
+        };
+        let return_raw = if let FormatAttr::Raw = return_format {
+            quote! {
+                impl<#args_generics_with_bounds> _interface::ToBytes<_interface::Raw> for Return<#args_generics_idents>
+                #where_clause
+                #return_type: _interface::ToBytes<_interface::Raw>,
+                {
+                    type Error = <#return_type as _interface::ToBytes<_interface::Raw>>::Error;
+
+                    fn to_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+                        _interface::ToBytes::<_interface::Raw>::to_bytes(&self.value)
+                    }
                 }
             }
+        } else {
+            quote!()
         };
-        let return_serializer_skip = {
+        let return_serializer_bounds = {
             use crate::core_impl::info_extractor::inputs;
             let recv_kind = &self.inputs.receiver_kind;
             if matches!(
                 recv_kind,
-                inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
+                inputs::ReceiverKind::StatelessInit | inputs::ReceiverKind::StatelessMigrate
             ) {
                 quote! {
-                    #[borsh_skip]
+                    _State: near_sdk::borsh::BorshSerialize
                 }
             } else {
-                quote! {
-                    #[serde(skip)]
-                }
+                quote!()
             }
         };
-        let return_serializer_bounds = {
+
+        // how the value that the method returns becomes a `Return`
+        let return_from_method = {
             use crate::core_impl::info_extractor::inputs;
             let recv_kind = &self.inputs.receiver_kind;
-            if matches!(
-                recv_kind,
-                inputs::ReceiverKind::Owned | inputs::ReceiverKind::StatelessInit
-            ) {
-                quote! {
-                    _State: near_sdk::borsh::BorshSerialize
+            let method_return_type = match &self.ret {
+                syn::ReturnType::Default => quote! {()},
+                syn::ReturnType::Type(_t, ty) => quote! {#ty},
+            };
+            let from_method_doc_str = format!(
+                " Creates the `Return` from the value returned by {}",
+                &method_link_dot_str
+            );
+            match (recv_kind, &self.result_types) {
+                // owned, init and migrate methods also return the state,
+                // so they are handled when serving
+                (
+                    inputs::ReceiverKind::Owned
+                    | inputs::ReceiverKind::StatelessInit
+                    | inputs::ReceiverKind::StatelessMigrate,
+                    _,
+                ) => quote!(),
+                (_, Some((_ok, err))) => {
+                    let error_encoding = self
+                        .attrs
+                        .handle_result
+                        .as_ref()
+                        .map(|h| h.error)
+                        .unwrap_or_default()
+                        .quote_marker();
+                    let error_encoding_doc_str = format!(
+                        " How the `Err` value returned by {} is encoded when the method aborts.",
+                        &method_link_str
+                    );
+                    quote! {
+                        #[doc = #mod_doc_str]
+                        #[doc = ""]
+                        #[doc = #error_encoding_doc_str]
+                        pub type ErrorEncoding = #error_encoding;
+
+                        impl<#args_generics_with_bounds> Return<#args_generics_idents>
+                        #where_clause
+                        serve::Serve<#args_generics_idents>: Default,
+                        ErrorEncoding: _interface::handle_result::ErrorEncoding<#err>,
+                        {
+                            #[doc = #from_method_doc_str]
+                            #[doc = ""]
+                            #[doc = " Aborts the method if it returned an `Err` value."]
+                            pub fn from_method_return(ret: #method_return_type) -> Self {
+                                let value = _interface::handle_result::unwrap_or_panic::<
+                                    _,
+                                    _,
+                                    ErrorEncoding
+                                >(ret);
+                                Return {
+                                    value,
+                                    _phantom: Default::default(),
+                                }
+                            }
+                        }
+                    }
                 }
+                (_, None) => quote! {
+                    impl<#args_generics_with_bounds> Return<#args_generics_idents>
+                    #where_clause
+                    serve::Serve<#args_generics_idents>: Default
+                    {
+                        #[doc = #from_method_doc_str]
+                        pub fn from_method_return(ret: #method_return_type) -> Self {
+                            Return {
+                                value: ret,
+                                _phantom: Default::default(),
+                            }
+                        }
+                    }
+                },
+            }
+        };
+
+        let arg_names = fake_args
+            .iter()
+            .map(|a| {
+                let pat = &a.pat;
+                quote!(#pat).to_string()
+            })
+            .collect::<Vec<_>>();
+        let arg_types = fake_args
+            .iter()
+            .map(|a| {
+                let ty = &a.ty;
+                super::type_str(&quote!(#ty))
+            })
+            .collect::<Vec<_>>();
+        // init and migrate methods don't return anything to the caller
+        let return_type_str = {
+            use crate::core_impl::info_extractor::inputs;
+            let return_type_str = super::type_str(&return_type);
+            if return_type_str == "()"
+                || matches!(
+                    &self.inputs.receiver_kind,
+                    inputs::ReceiverKind::StatelessInit | inputs::ReceiverKind::StatelessMigrate
+                )
+            {
+                quote!(None)
             } else {
-                quote!()
+                quote!(Some(#return_type_str))
             }
         };
+        let arg_names_doc_str = format!(" The names of the arguments of {}", &method_link_dot_str);
+        let arg_defaults = self
+            .inputs
+            .args
+            .iter()
+            .map(|a| a.attr.default.is_some())
+            .collect::<Vec<_>>();
+        let arg_defaults_doc_str = format!(
+            " Whether each argument of {} has a default value, and so may be missing from the input.",
+            &method_link_str
+        );
+        let arg_types_doc_str = format!(
+            " The types of the arguments of {}, as they are in [`ClientArgs`].",
+            &method_link_str
+        );
+        let return_type_doc_str = format!(
+            " The type of the value returned by {}, if any.",
+            &method_link_str
+        );
+
+        // with the `schemars` feature, the `Args` and json `Return`
+        // structures also derive `JsonSchema`, with explicit bounds so
+        // that `_State: JsonSchema` is not required, and with a fixed
+        // name, eg. `FtTransferArgs`, that doesn't include `_State`
+        let schema_derive = |suffix: &str, bounds: Vec<String>| {
+            use inflector::Inflector;
+            let name = format!("{}{}", original_method_name.to_pascal_case(), suffix);
+            let bound = bounds.join(", ");
+            quote! {
+                #[derive(_interface::schemars::JsonSchema)]
+                #[schemars(crate = "_interface::schemars", rename = #name, bound = #bound)]
+            }
+        };
+        let interface_alias = syn::Ident::new("_interface", proc_macro2::Span::call_site());
+        let schema_field =
+            |ty: &syn::Type| match super::schema_type(ty, &interface_alias, &near_sdk) {
+                Some(schema_ty) => {
+                    let schema_ty_str = quote!(#schema_ty).to_string();
+                    (
+                        format!("{}: _interface::schemars::JsonSchema", schema_ty_str),
+                        quote! { #[schemars(with = #schema_ty_str)] },
+                    )
+                }
+                None => (
+                    format!("{}: _interface::schemars::JsonSchema", quote!(#ty)),
+                    quote!(),
+                ),
+            };
+        let (args_schema, args_schema_attrs) = if cfg!(feature = "schemars") {
+            let (bounds, attrs): (Vec<_>, Vec<_>) =
+                fake_args.iter().map(|a| schema_field(&a.ty)).unzip();
+            (schema_derive("Args", bounds), attrs)
+        } else {
+            (quote!(), fake_args.iter().map(|_| quote!()).collect())
+        };
+        let (return_schema, return_schema_attr) = match (cfg!(feature = "schemars"), return_format)
+        {
+            (true, FormatAttr::Json) => {
+                let ty: syn::Type = syn::parse2(return_type.clone())?;
+                let (bound, attr) = schema_field(&ty);
+                (schema_derive("Return", vec![bound]), attr)
+            }
+            _ => (quote!(), quote!()),
+        };
+
+        let default_gas = match self.attrs.default_gas {
+            Some(gas) => {
+                let gas = proc_macro2::Literal::u64_unsuffixed(gas);
+                quote!(Some(#gas))
+            }
+            None => quote!(None),
+        };
+        let default_deposit = match self.attrs.default_deposit {
+            Some(deposit) => {
+                let deposit = proc_macro2::Literal::u128_unsuffixed(deposit);
+                quote!(Some(#deposit))
+            }
+            None => quote!(None),
+        };
+        let default_gas_doc_str = format!(
+            " The gas that clients should attach by default when calling {}",
+            &method_link_dot_str
+        );
+        let default_deposit_doc_str = format!(
+            " The deposit, in yoctoNEAR, that clients should attach by default when calling {}",
+            &method_link_dot_str
+        );
+
+        let args_defaults = self.args_defaults(&method_link_str);
+        let client_args = self.client_args(original_method_ident, trait_info);
+        let client_args_init = self.client_args_init();
+        let required_args = self.required_args();
+        let args_setters = self.args_setters(quote!(self.args));
+        let args_raw = self.args_raw(trait_info);
+        let args_positional = self.args_positional(trait_info);
+        let args_format_marker = self.attrs.args_format.quote_marker();
+        let args_format_doc_str = format!(
+            " The serialization format of the [`Args`] required by {}",
+            &method_link_dot_str
+        );
+        // the request builders are only generic over `_State` if needed
+        let with_state = self.client_needs_state(trait_info);
+        let (req_generics_with_bounds, req_generics_idents, req_phantom) =
+            self.client_generics(trait_info, with_state);
+        let req_where_clause = self.where_clause(trait_info, with_state);
+
+        let emits = &self.attrs.emits;
+        let emits_doc_str = format!(" The events that {} may emit.", &method_link_str);
 
         let q = Ok(quote! {
             #[doc = #mod_doc_str]
@@ -237,10 +465,58 @@ impl TraitItemMethodInfo {
                 use super::*;
                 use #near_sdk as _near_sdk;
                 use #internal_interface as _interface;
+                #return_serializer_use
 
-                pub use serve::Serve;
+                pub use serve::{Serve, ServeAs};
                 pub use request::Request;
 
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #args_format_doc_str]
+                pub type ArgsFormat = #args_format_marker;
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #return_format_doc_str]
+                pub type ReturnFormat = #return_format_marker;
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #arg_names_doc_str]
+                pub const ARG_NAMES: &[&str] = &[#(#arg_names),*];
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #arg_types_doc_str]
+                pub const ARG_TYPES: &[&str] = &[#(#arg_types),*];
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #arg_defaults_doc_str]
+                pub const ARG_DEFAULTS: &[bool] = &[#(#arg_defaults),*];
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #return_type_doc_str]
+                pub const RETURN_TYPE: Option<&str> = #return_type_str;
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #default_gas_doc_str]
+                pub const DEFAULT_GAS: Option<u64> = #default_gas;
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #default_deposit_doc_str]
+                pub const DEFAULT_DEPOSIT: Option<u128> = #default_deposit;
+
+                #[doc = #mod_doc_str]
+                #[doc = ""]
+                #[doc = #emits_doc_str]
+                pub const EMITS: &[_interface::EventMetadata] = &[
+                    #(<#emits as _interface::Event>::METADATA),*
+                ];
+
                 #[doc = #mod_doc_str]
                 #[doc = ""]
                 #[doc = " Represents the arguments required by "]
@@ -249,24 +525,29 @@ impl TraitItemMethodInfo {
                 #(#attr_docs)*
                 #[derive(_near_sdk::serde::Serialize, _near_sdk::serde::Deserialize)]
                 #[serde(crate = "_near_sdk::serde")]
+                #args_strict
+                #args_schema
                 pub struct
                 Args< //
+                    #args_lifetime
                     #args_generics_with_bounds
                 >
                 #where_clause
                 {
-                    #( #args_forward_attrs pub #fake_args,)*
-                    #[serde(skip)]
+                    #( #args_forward_attrs #args_schema_attrs #args_borrow_attrs #args_default_attrs pub #args_fields,)*
+                    // an explicit default path, otherwise serde would
+                    // require `_State: Default`
+                    #[serde(skip, default = "serve::Serve::default")]
                     pub _phantom: serve::Serve< //
                         #args_generics_idents
                     >,
                 }
 
-                impl <#args_generics_with_bounds> Args<#args_generics_idents>
+                impl <#args_lifetime #args_generics_with_bounds> Args<#args_lifetime #args_generics_idents>
                 #where_clause
                 serve::Serve<#args_generics_idents>: Default
                 {
-                    pub fn new(#(#fake_args,)*) -> Args<#args_generics_idents> {
+                    pub fn new(#(#args_fields,)*) -> Args<#args_lifetime #args_generics_idents> {
                         Args {
                             _phantom: serve::Serve::default(),
                             #(#args_pats),*
@@ -274,6 +555,14 @@ impl TraitItemMethodInfo {
                     }
                 }
 
+                #args_defaults
+
+                #client_args
+
+                #args_raw
+
+                #args_positional
+
                 #[doc = #mod_doc_str]
                 #[doc = ""]
                 #[doc = " Represents the return given by "]
@@ -281,12 +570,15 @@ impl TraitItemMethodInfo {
                 #[doc = ""]
                 #(#attr_docs)*
                 #return_serializer
+                #return_schema
                 pub struct Return< //
                     #args_generics_with_bounds
                 >
                 #where_clause
                 #return_serializer_bounds
                 {
+                    #return_value_bound
+                    #return_schema_attr
                     pub value: #return_type,
                     // phantom datas
                     #return_serializer_skip
@@ -295,6 +587,10 @@ impl TraitItemMethodInfo {
                     >
                 }
 
+                #return_from_method
+
+                #return_raw
+
                 #[doc = #mod_doc_str]
                 #[doc = ""]
                 #(#attr_docs)*
@@ -316,9 +612,29 @@ impl TraitItemMethodInfo {
                     #[doc = "`] for the server contract struct."]
                     #[doc = ""]
                     #(#attr_docs)*
-                    #[derive(Default)]
-                    pub struct Serve< //
+                    #[allow(type_alias_bounds)]
+                    pub type Serve< //
+                        #args_generics_with_bounds
+                    > = ServeAs<#args_generics_idents Name>;
+
+                    #[doc = #mod_doc_str]
+                    #[doc = ""]
+                    #[doc = " The name that "]
+                    #[doc = #method_link_str]
+                    #[doc = " is exported as when it has no prefix."]
+                    pub struct Name;
+
+                    impl _interface::ExportedName for Name {
+                        const NAME: &'static str = #original_method_name;
+                    }
+
+                    #[doc = #mod_doc_str]
+                    #[doc = ""]
+                    #[doc = " The same as [`Serve`], but the method is exported as the"]
+                    #[doc = " `_Name`, which the `extern_*!` macros use for their prefix."]
+                    pub struct ServeAs< //
                         #args_generics_with_bounds
+                        _Name,
                     >
                     #where_clause
                     {
@@ -335,6 +651,23 @@ impl TraitItemMethodInfo {
                         _method_types: ( //
                             #(std::marker::PhantomData<#args_method_generic_type_idents>,)*
                         ),
+                        _name: std::marker::PhantomData<_Name>,
+                    }
+
+                    // not derived, since that would require `_State: Default`
+                    impl<#args_generics_with_bounds _Name> Default for ServeAs<#args_generics_idents _Name>
+                    #where_clause
+                    {
+                        fn default() -> Self {
+                            Self {
+                                _trait_lifetimes: Default::default(),
+                                _method_lifetimes: Default::default(),
+                                _state_type: Default::default(),
+                                _trait_types: Default::default(),
+                                _method_types: Default::default(),
+                                _name: Default::default(),
+                            }
+                        }
                     }
                 }
 
@@ -356,12 +689,11 @@ impl TraitItemMethodInfo {
                     #[doc = " still need to be defined."]
                     #[doc = ""]
                     #(#attr_docs)*
-                    pub struct Request<#args_generics_with_bounds>(serve::Serve<#args_generics_idents>)
-                    #where_clause;
+                    pub struct Request<#req_generics_with_bounds>(#req_phantom)
+                    #req_where_clause;
 
-                    impl<#args_generics_with_bounds> Request<#args_generics_idents>
-                    #where_clause
-                    serve::Serve<#args_generics_idents>: Default
+                    impl<#req_generics_with_bounds> Request<#req_generics_idents>
+                    #req_where_clause
                     {
                         #[doc = #mod_doc_str]
                         #[doc = ""]
@@ -376,11 +708,11 @@ impl TraitItemMethodInfo {
                         #[doc = ""]
                         #[doc = " See also [`Self::contract_with_renamed_method()`] if the method's name has been renamed."]
                         #(#attr_docs)*
-                        pub fn contract(contract_being_called: _near_sdk::AccountId) -> MethodRequest<#args_generics_idents> {
+                        pub fn contract(contract_being_called: _near_sdk::AccountId) -> MethodRequest<#req_generics_idents> {
                             MethodRequest {
                                 contract_being_called,
                                 method_name: #original_method_name.to_string(),
-                                _phantom: serve::Serve::default(),
+                                _phantom: Default::default(),
                             }
                         }
 
@@ -396,13 +728,32 @@ impl TraitItemMethodInfo {
                         #[doc = " See also [`Self::contract()`] if the method's name has not been renamed."]
                         #[doc = ""]
                         #(#attr_docs)*
-                        pub fn contract_with_renamed_method(contract_being_called: _near_sdk::AccountId, method_name: String) -> MethodRequest<#args_generics_idents> {
+                        pub fn contract_with_renamed_method(contract_being_called: _near_sdk::AccountId, method_name: String) -> MethodRequest<#req_generics_idents> {
                             MethodRequest {
                                 contract_being_called,
                                 method_name,
-                                _phantom: serve::Serve::default(),
+                                _phantom: Default::default(),
                             }
                         }
+
+                        #[doc = #mod_doc_str]
+                        #[doc = ""]
+                        #[doc = #builder_doc_str]
+                        #[doc = ""]
+                        #[doc = " Sets the `contract_being_called` ([`AccountId`]("]
+                        #[doc = #account_id_str]
+                        #[doc = "))"]
+                        #[doc = " and the `method_name` being called, as it was exported with a `prefix`."]
+                        #[doc = ""]
+                        #[doc = " See also [`Self::contract_with_renamed_method()`]."]
+                        #[doc = ""]
+                        #(#attr_docs)*
+                        pub fn contract_with_prefixed_method(contract_being_called: _near_sdk::AccountId, prefix: &str) -> MethodRequest<#req_generics_idents> {
+                            Self::contract_with_renamed_method(
+                                contract_being_called,
+                                format!("{}{}", prefix, #original_method_name),
+                            )
+                        }
                     }
 
                     #[doc = #mod_doc_str]
@@ -413,17 +764,16 @@ impl TraitItemMethodInfo {
                     #[doc = " still need to be defined."]
                     #[doc = ""]
                     #(#attr_docs)*
-                    pub struct MethodRequest<#args_generics_with_bounds>
-                    #where_clause
+                    pub struct MethodRequest<#req_generics_with_bounds>
+                    #req_where_clause
                     {
                         contract_being_called: _near_sdk::AccountId,
                         method_name: String,
-                        _phantom: serve::Serve<#args_generics_idents>
+                        _phantom: #req_phantom
                     }
 
-                    impl<#args_generics_with_bounds> MethodRequest<#args_generics_idents>
-                    #where_clause
-                    serve::Serve<#args_generics_idents>: Default
+                    impl<#req_generics_with_bounds> MethodRequest<#req_generics_idents>
+                    #req_where_clause
                     {
                         #[doc = #mod_doc_str]
                         #[doc = ""]
@@ -432,8 +782,11 @@ impl TraitItemMethodInfo {
                         #[doc = " Sets the arguments for the call."]
                         #[doc = ""]
                         #(#attr_docs)*
-                        pub fn args(self, #(#fake_args,)*) -> ArgsRequest<#args_generics_idents> {
-                            let args = Args::new(#(#args_pats),*);
+                        pub fn args(self, #(#required_args,)*) -> ArgsRequest<#req_generics_idents> {
+                            let args = ClientArgs {
+                                #client_args_init
+                                _phantom: Default::default(),
+                            };
                             ArgsRequest::new(
                                 self.method_name,
                                 self.contract_being_called,
@@ -454,21 +807,21 @@ impl TraitItemMethodInfo {
                     #[doc = ") to be attached still need to be defined."]
                     #[doc = ""]
                     #(#attr_docs)*
-                    pub struct ArgsRequest<#args_generics_with_bounds>
-                    #where_clause
+                    pub struct ArgsRequest<#req_generics_with_bounds>
+                    #req_where_clause
                     {
                         method_name: String,
                         contract_being_called: _near_sdk::AccountId,
-                        args: Args<#args_generics_idents>,
+                        args: ClientArgs<#req_generics_idents>,
                     }
 
-                    impl<#args_generics_with_bounds> ArgsRequest<#args_generics_idents>
-                    #where_clause
+                    impl<#req_generics_with_bounds> ArgsRequest<#req_generics_idents>
+                    #req_where_clause
                     {
                         pub fn new(
                             method_name: String,
                             contract_being_called: _near_sdk::AccountId,
-                            args: Args<#args_generics_idents>
+                            args: ClientArgs<#req_generics_idents>
                         ) -> Self {
                             Self {
                                 method_name,
@@ -477,6 +830,8 @@ impl TraitItemMethodInfo {
                             }
                         }
 
+                        #args_setters
+
                         #[doc = #mod_doc_str]
                         #[doc = ""]
                         #[doc = #builder_doc_str]
@@ -486,7 +841,7 @@ impl TraitItemMethodInfo {
                         #[doc = ") to be sent for the call."]
                         #[doc = ""]
                         #(#attr_docs)*
-                        pub fn send_amount(self, send_amount: _near_sdk::Balance) -> AmountRequest<#args_generics_idents> {
+                        pub fn send_amount(self, send_amount: _near_sdk::Balance) -> AmountRequest<#req_generics_idents> {
                             AmountRequest {
                                 method_name: self.method_name,
                                 contract_being_called: self.contract_being_called,
@@ -507,7 +862,7 @@ impl TraitItemMethodInfo {
                         #[doc = ") to be sent to zero."]
                         #[doc = ""]
                         #(#attr_docs)*
-                        pub fn prepaid_gas(self, maximum_allowed_consumption: _near_sdk::Gas) -> GasRequest<#args_generics_idents> {
+                        pub fn prepaid_gas(self, maximum_allowed_consumption: _near_sdk::Gas) -> GasRequest<#req_generics_idents> {
                             GasRequest {
                                 method_name: self.method_name,
                                 contract_being_called: self.contract_being_called,
@@ -527,17 +882,17 @@ impl TraitItemMethodInfo {
                     #[doc = ") to be attached still need to be defined."]
                     #[doc = ""]
                     #(#attr_docs)*
-                    pub struct AmountRequest<#args_generics_with_bounds>
-                    #where_clause
+                    pub struct AmountRequest<#req_generics_with_bounds>
+                    #req_where_clause
                     {
                         method_name: String,
                         contract_being_called: _near_sdk::AccountId,
-                        args: Args<#args_generics_idents>,
+                        args: ClientArgs<#req_generics_idents>,
                         send_amount: _near_sdk::Balance,
                     }
 
-                    impl<#args_generics_with_bounds> AmountRequest<#args_generics_idents>
-                    #where_clause
+                    impl<#req_generics_with_bounds> AmountRequest<#req_generics_idents>
+                    #req_where_clause
                     {
                         #[doc = #mod_doc_str]
                         #[doc = ""]
@@ -548,7 +903,7 @@ impl TraitItemMethodInfo {
                         #[doc = ") to be attached for the call."]
                         #[doc = ""]
                         #(#attr_docs)*
-                        pub fn prepaid_gas(self, maximum_allowed_consumption: _near_sdk::Gas) -> GasRequest<#args_generics_idents> {
+                        pub fn prepaid_gas(self, maximum_allowed_consumption: _near_sdk::Gas) -> GasRequest<#req_generics_idents> {
                             GasRequest {
                                 method_name: self.method_name,
                                 contract_being_called: self.contract_being_called,
@@ -566,19 +921,19 @@ impl TraitItemMethodInfo {
                     #[doc = " This represents a request ready to be sent into the server contract."]
                     #[doc = ""]
                     #(#attr_docs)*
-                    pub struct GasRequest<#args_generics_with_bounds>
-                    #where_clause
+                    pub struct GasRequest<#req_generics_with_bounds>
+                    #req_where_clause
                     {
                         method_name: String,
                         contract_being_called: _near_sdk::AccountId,
-                        args: Args<#args_generics_idents>,
+                        args: ClientArgs<#req_generics_idents>,
                         send_amount: _near_sdk::Balance,
                         prepaid_gas: _near_sdk::Gas,
                     }
 
-                    impl<#args_generics_with_bounds> GasRequest<#args_generics_idents>
-                    #where_clause
-                    Args<#args_generics_idents>: _interface::ToBytes<_interface::Json>
+                    impl<#req_generics_with_bounds> GasRequest<#req_generics_idents>
+                    #req_where_clause
+                    ClientArgs<#req_generics_idents>: _interface::ToBytes<ArgsFormat>
                     {
 
                         #[doc = #mod_doc_str]
@@ -596,11 +951,9 @@ impl TraitItemMethodInfo {
                         #[doc = ""]
                         #(#attr_docs)*
                         pub fn request(self) -> _near_sdk::Promise {
-                            use _interface::ToBytes;
                             _near_sdk::Promise::new(self.contract_being_called).function_call(
                                 self.method_name.to_string(),
-                                self.args
-                                    .to_bytes()
+                                _interface::ToBytes::<ArgsFormat>::to_bytes(&self.args)
                                     .expect("Failed to serialize the cross contract args."),
                                 self.send_amount,
                                 self.prepaid_gas,
@@ -608,6 +961,20 @@ impl TraitItemMethodInfo {
                         }
 
                     }
+
+                    #[doc = #mod_doc_str]
+                    #[doc = ""]
+                    #[doc = " Maps a `_State` into the [`Request`] builder for it, which is the"]
+                    #[doc = " same for every `_State` if the method doesn't mention `Self`."]
+                    pub trait StateRequest {
+                        type Request;
+                    }
+
+                    impl<#args_generics_with_bounds> StateRequest for serve::Serve<#args_generics_idents>
+                    #where_clause
+                    {
+                        type Request = Request<#req_generics_idents>;
+                    }
                 }
 
             }
@@ -619,3 +986,511 @@ impl TraitItemMethodInfo {
         q
     }
 }
+
+impl TraitItemMethodInfo {
+    /// The arguments as they are owned by clients, such as in the
+    /// `ClientArgs` structure, where references are replaced by owned
+    /// values.
+    fn fake_args(&self) -> Vec<syn::PatType> {
+        self.inputs
+            .args
+            .iter()
+            .map(|a| {
+                let mut arg = a.owned_arg().clone();
+                arg.attrs.clear();
+                arg
+            })
+            .collect()
+    }
+
+    /// The arguments without default values, which are always set by
+    /// clients.
+    fn required_args(&self) -> Vec<syn::PatType> {
+        self.inputs
+            .args
+            .iter()
+            .zip(self.fake_args())
+            .filter(|(a, _)| a.attr.default.is_none())
+            .map(|(_, arg)| arg)
+            .collect()
+    }
+
+    /// The fields of a new `ClientArgs`, from the required arguments,
+    /// where arguments with default values are not yet set.
+    fn client_args_init(&self) -> TokenStream2 {
+        let fields = self.inputs.args.iter().map(|a| {
+            let pat = &a.arg.pat;
+            match &a.attr.default {
+                Some(_) => quote!(#pat: None),
+                None => quote!(#pat),
+            }
+        });
+        quote!(#(#fields,)*)
+    }
+
+    /// The setters for the arguments with default values, which are
+    /// otherwise not sent, and so the server uses their default.
+    ///
+    /// `args` is the `ClientArgs` being set, eg. `self.args`.
+    fn args_setters(&self, args: TokenStream2) -> TokenStream2 {
+        let setters = self
+            .inputs
+            .args
+            .iter()
+            .zip(self.fake_args())
+            .filter(|(a, _)| a.attr.default.is_some())
+            .map(|(_, arg)| {
+                let pat = &arg.pat;
+                let ty = &arg.ty;
+                let setter = quote::format_ident!("with_{}", quote!(#pat).to_string());
+                let doc_str = format!(
+                    " Sets the `{}` argument, which otherwise has its default value.",
+                    quote!(#pat)
+                );
+                quote! {
+                    #[doc = #doc_str]
+                    pub fn #setter(mut self, #pat: #ty) -> Self {
+                        #args.#pat = Some(#pat);
+                        self
+                    }
+                }
+            });
+        quote!(#(#setters)*)
+    }
+
+    /// Generates the `defaults` mod, with a function for the default
+    /// value of each argument that has one.
+    fn args_defaults(&self, method_link_str: &str) -> TokenStream2 {
+        let defaults = self
+            .inputs
+            .args
+            .iter()
+            .filter_map(|a| {
+                let expr = a.attr.default.as_ref()?;
+                let pat = &a.arg.pat;
+                let ty = a.default_ty();
+                // borrowed args are held as a `Cow`, which is built
+                // from the `&str` or `&[u8]` default
+                let expr = if a.borrowed_arg.is_some() {
+                    quote!(::std::convert::Into::into(#expr))
+                } else {
+                    quote!(#expr)
+                };
+                Some(quote! {
+                    pub fn #pat() -> #ty {
+                        #expr
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if defaults.is_empty() {
+            return quote!();
+        }
+        let doc_str = format!(
+            " The values of the arguments of {} that are missing from the input.",
+            method_link_str
+        );
+        quote! {
+            #[doc = #doc_str]
+            pub mod defaults {
+                use super::*;
+
+                #(#defaults)*
+            }
+        }
+    }
+
+    /// The arguments as they are in the `Args` structure, where `&str`,
+    /// `&[u8]` and `Cow` args borrow from the input for `'_args`.
+    fn args_fields(&self) -> Vec<syn::PatType> {
+        self.inputs
+            .args
+            .iter()
+            .map(|a| {
+                let mut arg = a.args_field();
+                arg.attrs.clear();
+                arg
+            })
+            .collect()
+    }
+
+    /// The `'_args` lifetime of the `Args` structure, for methods that
+    /// have arguments borrowed from the input.
+    pub fn args_lifetime(&self) -> TokenStream2 {
+        if self.inputs.has_borrowed_args() {
+            quote!('_args,)
+        } else {
+            quote!()
+        }
+    }
+
+    /// The `where` clause of the generated items.
+    ///
+    /// Without `with_state`, the bounds on `_State` are not included,
+    /// such as for the client items of traits that don't mention `Self`.
+    fn where_clause(&self, trait_info: &ItemTraitInfo, with_state: bool) -> TokenStream2 {
+        let args_trait_lifetime_idents = trait_info.generics.lifetimes.keys().collect::<Vec<_>>();
+        let args_trait_generic_type_idents = trait_info.generics.types.keys().collect::<Vec<_>>();
+        let args_trait_generic_const_idents = trait_info.generics.consts.keys().collect::<Vec<_>>();
+
+        let self_lifetime_bounds = &trait_info.self_lifetime_bounds;
+        let self_lifetime_bounds_q = if self_lifetime_bounds.is_empty() {
+            quote! {}
+        } else {
+            quote! {_State: #(#self_lifetime_bounds )+*,}
+        };
+
+        let implicit_self_trait_bound = {
+            let trait_name = &trait_info.original_ident;
+            if !args_trait_lifetime_idents.is_empty()
+                || !args_trait_generic_type_idents.is_empty()
+                || !args_trait_generic_const_idents.is_empty()
+            {
+                quote! {
+                    _State: #trait_name < //
+                      #(#args_trait_lifetime_idents,)*
+                      #(#args_trait_generic_type_idents,)*
+                      #(#args_trait_generic_const_idents,)*
+                    >,
+                }
+            } else {
+                quote! {_State: #trait_name ,}
+            }
+        };
+
+        let self_trait_bounds = &trait_info.self_trait_bounds;
+        let self_trait_bounds_q = if self_trait_bounds.is_empty() {
+            quote! {}
+        } else {
+            quote! {_State: #(#self_trait_bounds )+*,}
+        };
+
+        let trait_lifetime_where_clauses = trait_info
+            .generics
+            .lifetime_bounds
+            .values()
+            .collect::<Vec<_>>();
+        // bounds directly on `_State`, such as `Self: Sized`
+        let is_state_bound = |pt: &&syn::PredicateType| {
+            let ty = &pt.bounded_ty;
+            quote!(#ty).to_string() == "_State"
+        };
+        let trait_type_where_clauses = trait_info
+            .generics
+            .type_bounds
+            .values()
+            .filter(|pt| with_state || !is_state_bound(pt))
+            .collect::<Vec<_>>();
+
+        let method_lifetime_where_clauses =
+            self.generics.lifetime_bounds.values().collect::<Vec<_>>();
+        let method_type_where_clauses = self
+            .generics
+            .type_bounds
+            .values()
+            .filter(|pt| with_state || !is_state_bound(pt))
+            .collect::<Vec<_>>();
+
+        let state_bounds = if with_state {
+            quote! {
+                #self_lifetime_bounds_q
+                #self_trait_bounds_q
+                #implicit_self_trait_bound
+            }
+        } else {
+            quote!()
+        };
+
+        quote! {
+            where
+                #state_bounds
+                #(#trait_lifetime_where_clauses,)*
+                #(#method_lifetime_where_clauses,)*
+                #(#method_type_where_clauses,)*
+                #(#trait_type_where_clauses,)*
+        }
+    }
+}
+
+impl TraitItemMethodInfo {
+    /// The generics of the client items, in the same order as for
+    /// `Args`, where `_State` is only present `with_state`.
+    ///
+    /// Returns the generics with their bounds, their identifiers and
+    /// the phantom types for them.
+    fn client_generics(
+        &self,
+        trait_info: &ItemTraitInfo,
+        with_state: bool,
+    ) -> (TokenStream2, TokenStream2, TokenStream2) {
+        let trait_lifetime_idents = trait_info.generics.lifetimes.keys().collect::<Vec<_>>();
+        let trait_lifetimes = trait_info.generics.lifetimes.values();
+        let method_lifetime_idents = self.generics.lifetimes.keys().collect::<Vec<_>>();
+        let method_lifetimes = self.generics.lifetimes.values();
+        let trait_type_idents = trait_info.generics.types.keys().collect::<Vec<_>>();
+        let trait_types = trait_info.generics.types.values();
+        let method_type_idents = self.generics.types.keys().collect::<Vec<_>>();
+        let method_types = self.generics.types.values();
+        let trait_const_idents = trait_info.generics.consts.keys();
+        let trait_consts = trait_info.generics.consts.values();
+        let method_const_idents = self.generics.consts.keys();
+        let method_consts = self.generics.consts.values();
+        let state = if with_state {
+            quote!(_State,)
+        } else {
+            quote!()
+        };
+        let state_phantom = if with_state {
+            quote!(std::marker::PhantomData<_State>,)
+        } else {
+            quote!()
+        };
+
+        (
+            quote! {
+                #(#trait_lifetimes,)*
+                #(#method_lifetimes,)*
+                #state
+                #(#method_types,)*
+                #(#trait_types,)*
+                #(#trait_consts,)*
+                #(#method_consts,)*
+            },
+            quote! {
+                #(#trait_lifetime_idents,)*
+                #(#method_lifetime_idents,)*
+                #state
+                #(#method_type_idents,)*
+                #(#trait_type_idents,)*
+                #(#trait_const_idents,)*
+                #(#method_const_idents,)*
+            },
+            quote! {
+                (
+                    #(std::marker::PhantomData<&#trait_lifetime_idents ()>,)*
+                    #(std::marker::PhantomData<&#method_lifetime_idents ()>,)*
+                    #state_phantom
+                    #(std::marker::PhantomData<#method_type_idents>,)*
+                    #(std::marker::PhantomData<#trait_type_idents>,)*
+                )
+            },
+        )
+    }
+
+    /// Whether the client items of the method, such as `ClientArgs` and
+    /// the request builders, must be generic over `_State`.
+    pub fn client_needs_state(&self, trait_info: &ItemTraitInfo) -> bool {
+        trait_info.generics_mention_state() || !self.self_free
+    }
+
+    /// Generates the `ClientArgs` structure, which is what the trait's
+    /// `Client` serializes.
+    ///
+    /// Unlike `Args`, it's only generic over `_State` if the method
+    /// mentions `Self`.
+    pub fn client_args(
+        &self,
+        original_method_name: &syn::Ident,
+        trait_info: &ItemTraitInfo,
+    ) -> TokenStream2 {
+        let with_state = self.client_needs_state(trait_info);
+        let (generics_with_bounds, generics_idents, phantom) =
+            self.client_generics(trait_info, with_state);
+        let where_clause = self.where_clause(trait_info, with_state);
+        // args with default values are only sent if they are set
+        let fields = self
+            .inputs
+            .args
+            .iter()
+            .zip(self.fake_args())
+            .map(|(a, arg)| {
+                let forwarded_attr = &a.attr.forward_attr;
+                let forward_attrs = quote! { #( # [ #forwarded_attr ] )* };
+                match &a.attr.default {
+                    Some(_) => {
+                        let pat = &arg.pat;
+                        let ty = &arg.ty;
+                        quote! {
+                            #forward_attrs
+                            #[serde(skip_serializing_if = "Option::is_none")]
+                            pub #pat: Option<#ty>
+                        }
+                    }
+                    None => quote!(#forward_attrs pub #arg),
+                }
+            });
+        let args_setters = self.args_setters(quote!(self));
+        let doc_str = format!(
+            " The arguments of [`{}::{}()`], as they are sent by the [`request`] builders and by the trait's [`Client`](super::Client).",
+            &trait_info.original_ident, original_method_name
+        );
+
+        quote! {
+            #[doc = #doc_str]
+            #[derive(_near_sdk::serde::Serialize)]
+            #[serde(crate = "_near_sdk::serde")]
+            pub struct ClientArgs<#generics_with_bounds>
+            #where_clause
+            {
+                #(#fields,)*
+                #[serde(skip)]
+                pub _phantom: #phantom,
+            }
+
+            impl<#generics_with_bounds> ClientArgs<#generics_idents>
+            #where_clause
+            {
+                #args_setters
+            }
+        }
+    }
+
+    /// For raw args, generates the conversions between the bytes and
+    /// the `Args` and `ClientArgs` structures, which only have the
+    /// method's single argument.
+    pub fn args_raw(&self, trait_info: &ItemTraitInfo) -> TokenStream2 {
+        if self.attrs.args_format != FormatAttr::Raw {
+            return quote!();
+        }
+        let with_state = self.client_needs_state(trait_info);
+        let (generics_with_bounds, generics_idents, _phantom) =
+            self.client_generics(trait_info, true);
+        let (client_generics_with_bounds, client_generics_idents, _phantom) =
+            self.client_generics(trait_info, with_state);
+        let where_clause = self.where_clause(trait_info, true);
+        let client_where_clause = self.where_clause(trait_info, with_state);
+        let args_lifetime = self.args_lifetime();
+        let args_field = &self.args_fields()[0];
+        let (pat, args_ty) = (&args_field.pat, &args_field.ty);
+        let ty = &self.fake_args()[0].ty;
+
+        quote! {
+            impl<'_args, #generics_with_bounds> _interface::FromBorrowedBytes<'_args, _interface::Raw> for Args<#args_lifetime #generics_idents>
+            #where_clause
+            serve::Serve<#generics_idents>: Default,
+            #args_ty: _interface::FromBorrowedBytes<'_args, _interface::Raw>,
+            {
+                type Error = <#args_ty as _interface::FromBorrowedBytes<'_args, _interface::Raw>>::Error;
+
+                fn from_borrowed_bytes(bytes: &'_args [u8]) -> Result<Self, Self::Error> {
+                    Ok(Args {
+                        #pat: _interface::FromBorrowedBytes::<_interface::Raw>::from_borrowed_bytes(bytes)?,
+                        _phantom: serve::Serve::default(),
+                    })
+                }
+            }
+
+            impl<#client_generics_with_bounds> _interface::ToBytes<_interface::Raw> for ClientArgs<#client_generics_idents>
+            #client_where_clause
+            #ty: _interface::ToBytes<_interface::Raw>,
+            {
+                type Error = <#ty as _interface::ToBytes<_interface::Raw>>::Error;
+
+                fn to_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+                    _interface::ToBytes::<_interface::Raw>::to_bytes(&self.#pat)
+                }
+            }
+        }
+    }
+
+    /// For json_positional args, generates the conversions between the
+    /// json array and the `Args` and `ClientArgs` structures, through
+    /// the tuple of the arguments.
+    pub fn args_positional(&self, trait_info: &ItemTraitInfo) -> TokenStream2 {
+        if self.attrs.args_format != FormatAttr::JsonPositional {
+            return quote!();
+        }
+        let with_state = self.client_needs_state(trait_info);
+        let (generics_with_bounds, generics_idents, _phantom) =
+            self.client_generics(trait_info, true);
+        let (client_generics_with_bounds, client_generics_idents, _phantom) =
+            self.client_generics(trait_info, with_state);
+        let where_clause = self.where_clause(trait_info, true);
+        let client_where_clause = self.where_clause(trait_info, with_state);
+        let args_lifetime = self.args_lifetime();
+        let args_fields = self.args_fields();
+        let pats = args_fields.iter().map(|a| &a.pat).collect::<Vec<_>>();
+        let args_tys = args_fields.iter().map(|a| &a.ty).collect::<Vec<_>>();
+        let fake_args = self.fake_args();
+        let tys = fake_args.iter().map(|a| &a.ty).collect::<Vec<_>>();
+
+        quote! {
+            impl<'_args, #generics_with_bounds> _interface::FromBorrowedBytes<'_args, _interface::JsonPositional> for Args<#args_lifetime #generics_idents>
+            #where_clause
+            serve::Serve<#generics_idents>: Default,
+            #(#args_tys: _near_sdk::serde::Deserialize<'_args>,)*
+            {
+                type Error = _interface::JsonPathError;
+
+                fn from_borrowed_bytes(bytes: &'_args [u8]) -> Result<Self, Self::Error> {
+                    let (#(#pats,)*): (#(#args_tys,)*) =
+                        _interface::FromBorrowedBytes::<_interface::JsonPositional>::from_borrowed_bytes(bytes)?;
+                    Ok(Args {
+                        #(#pats,)*
+                        _phantom: serve::Serve::default(),
+                    })
+                }
+            }
+
+            impl<#client_generics_with_bounds> _interface::ToBytes<_interface::JsonPositional> for ClientArgs<#client_generics_idents>
+            #client_where_clause
+            #(#tys: _near_sdk::serde::Serialize,)*
+            {
+                type Error = _near_sdk::serde_json::Error;
+
+                fn to_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+                    _interface::ToBytes::<_interface::JsonPositional>::to_bytes(&(#(&self.#pats,)*))
+                }
+            }
+        }
+    }
+
+    /// Generates the method of the trait's `Client`, which sets the
+    /// arguments of a request into the method.
+    pub fn client_method(
+        &self,
+        original_method_name: &syn::Ident,
+        trait_info: &ItemTraitInfo,
+    ) -> TokenStream2 {
+        let with_state = self.client_needs_state(trait_info);
+        let (_generics_with_bounds, generics_idents, _phantom) =
+            self.client_generics(trait_info, with_state);
+        let where_clause = self.where_clause(trait_info, with_state);
+        let method_mod_name = &self.attrs.method_mod_name;
+        let method_name_str = original_method_name.to_string();
+        let method_lifetimes = self.generics.lifetimes.values();
+        let method_types = self.generics.types.values();
+        let method_consts = self.generics.consts.values();
+        let required_args = self.required_args();
+        let client_args_init = self.client_args_init();
+        let doc_str = format!(
+            " Sets the arguments of a request into [`{}::{}()`].",
+            &trait_info.original_ident, original_method_name
+        );
+
+        quote! {
+            #[doc = #doc_str]
+            pub fn #original_method_name<
+                #(#method_lifetimes,)*
+                #(#method_types,)*
+                #(#method_consts,)*
+            >(
+                &self,
+                #(#required_args,)*
+            ) -> _interface::request::ArgsRequest<
+                #method_mod_name::ClientArgs<#generics_idents>,
+                #method_mod_name::ArgsFormat
+            >
+            #where_clause
+            {
+                let args = #method_mod_name::ClientArgs {
+                    #client_args_init
+                    _phantom: Default::default(),
+                };
+                _interface::request::Request::contract(self.contract_being_called.clone())
+                    .method(format!("{}{}", self.prefix, #method_name_str))
+                    .args(args)
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use darling::FromMeta;
+
+/// Attributes for enums that are groups of events.
+///
+/// eg. `#[contract(event(standard = "nep141", version = "1.0.0"))]`.
+#[derive(Debug, Clone, FromMeta)]
+pub struct EventAttr {
+    /// The name of the standard that defines the events.
+    pub standard: String,
+
+    /// The version of the standard.
+    pub version: String,
+}
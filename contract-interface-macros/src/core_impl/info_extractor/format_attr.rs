@@ -0,0 +1,29 @@
+use darling::FromMeta;
+
+/// The serialization format of a method's arguments or return value.
+#[derive(Debug, Clone, Copy, PartialEq, Default, FromMeta)]
+pub enum FormatAttr {
+    #[default]
+    #[darling(rename = "json")]
+    Json,
+    /// Json where the arguments are written as an array, in order.
+    #[darling(rename = "json_positional")]
+    JsonPositional,
+    #[darling(rename = "borsh")]
+    Borsh,
+    /// The bytes pass through untouched, for a single `Vec<u8>`.
+    #[darling(rename = "raw")]
+    Raw,
+}
+
+impl FormatAttr {
+    pub fn quote_marker(&self) -> proc_macro2::TokenStream {
+        use quote::quote;
+        match self {
+            FormatAttr::Json => quote!(_interface::Json),
+            FormatAttr::JsonPositional => quote!(_interface::JsonPositional),
+            FormatAttr::Borsh => quote!(_interface::Borsh),
+            FormatAttr::Raw => quote!(_interface::Raw),
+        }
+    }
+}
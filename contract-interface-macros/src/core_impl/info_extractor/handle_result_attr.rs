@@ -0,0 +1,77 @@
+use darling::FromMeta;
+
+/// Attributes for methods that return a `Result`.
+///
+/// eg. `#[contract(handle_result)]`.
+/// eg. `#[contract(handle_result(error = "json"))]`.
+#[derive(Debug, Clone, Default)]
+pub struct HandleResultAttr {
+    /// How the `Err` value is encoded when the method aborts.
+    pub error: ErrorEncodingAttr,
+}
+
+#[derive(Debug, FromMeta)]
+struct RawHandleResultAttr {
+    #[darling(default)]
+    error: Option<ErrorEncodingAttr>,
+}
+
+impl FromMeta for HandleResultAttr {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        let raw = RawHandleResultAttr::from_list(items)?;
+        Ok(Self {
+            error: raw.error.unwrap_or_default(),
+        })
+    }
+}
+
+/// How the `Err` value is encoded when the method aborts.
+#[derive(Debug, Clone, Copy, PartialEq, Default, FromMeta)]
+pub enum ErrorEncodingAttr {
+    #[default]
+    #[darling(rename = "display")]
+    Display,
+    #[darling(rename = "json")]
+    Json,
+    #[darling(rename = "code")]
+    Code,
+}
+
+impl ErrorEncodingAttr {
+    pub fn quote_marker(&self) -> proc_macro2::TokenStream {
+        use quote::quote;
+        match self {
+            ErrorEncodingAttr::Display => quote!(_interface::handle_result::DisplayError),
+            ErrorEncodingAttr::Json => quote!(_interface::handle_result::JsonError),
+            ErrorEncodingAttr::Code => quote!(_interface::handle_result::CodeError),
+        }
+    }
+}
+
+/// Gets the `T` and `E` from a `Result<T, E>` type.
+pub fn result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    let path = match ty {
+        syn::Type::Path(p) if p.qself.is_none() => &p.path,
+        _ => return None,
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Result" {
+        return None;
+    }
+    let args = match &last.arguments {
+        syn::PathArguments::AngleBracketed(a) => &a.args,
+        _ => return None,
+    };
+    let mut types = args.iter().filter_map(|a| match a {
+        syn::GenericArgument::Type(t) => Some(t),
+        _ => None,
+    });
+    match (types.next(), types.next(), types.next()) {
+        (Some(ok), Some(err), None) => Some((ok, err)),
+        _ => None,
+    }
+}
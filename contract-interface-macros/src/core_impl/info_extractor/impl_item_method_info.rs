@@ -2,6 +2,9 @@ use super::init_attr;
 use super::inputs::Inputs;
 use super::item_generics::Generics;
 use super::meta_attrs;
+use super::migrate_attr::MigrateAttr;
+use super::owned_return::{self, OwnedReturn};
+use super::trait_item_method_info;
 use crate::error;
 use darling::FromMeta;
 
@@ -21,6 +24,14 @@ pub struct ImplItemMethodInfo {
     pub inputs: Inputs,
 
     pub ret: syn::ReturnType,
+
+    /// How the return is split into the new state and the returned
+    /// value, for owned-`self` methods.
+    pub owned_return: Option<OwnedReturn>,
+
+    /// The type of the old state that is read from the storage,
+    /// for migrate methods.
+    pub old_state_ty: Option<syn::Type>,
 }
 
 #[derive(Debug, FromMeta)]
@@ -32,6 +43,9 @@ pub struct RawAttrs {
     #[darling(default)]
     init: Option<init_attr::InitAttr>,
 
+    #[darling(default)]
+    migrate: Option<MigrateAttr>,
+
     #[darling(default)]
     payable: Option<bool>,
 
@@ -40,6 +54,9 @@ pub struct RawAttrs {
 
     #[darling(default)]
     allow_temporary_state: Option<bool>,
+
+    #[darling(default)]
+    write_if_changed: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -50,16 +67,24 @@ pub struct Attrs {
 
     pub init: Option<init_attr::InitAttr>,
 
+    pub migrate: Option<MigrateAttr>,
+
     pub payable: bool,
 
+    /// Migrate methods are private unless `#[contract(private = false)]`
+    /// is set.
     pub private: bool,
 
     pub allow_temporary_state: bool,
+
+    /// The state is only written back if the method changed it,
+    /// by comparing it's serialized bytes before and after the call.
+    pub write_if_changed: bool,
 }
 
 impl ImplItemMethodInfo {
     /// Process the method and extract information important for near-sdk.
-    pub fn new(original: &mut syn::ImplItemMethod) -> error::Result<Self> {
+    pub fn new(original: &mut syn::ImplItemMethod, self_ty: &syn::Type) -> error::Result<Self> {
         let (contract_attr, non_contract_attr) =
             meta_attrs::partition_attrs(&original.attrs, "contract");
         original.attrs.clear();
@@ -78,14 +103,20 @@ impl ImplItemMethodInfo {
                 }),
                 init: attrs.init,
                 payable: matches!(attrs.payable, Some(true)),
-                private: matches!(attrs.private, Some(true)),
+                private: attrs.private.unwrap_or(attrs.migrate.is_some()),
+                migrate: attrs.migrate,
                 allow_temporary_state: matches!(attrs.allow_temporary_state, Some(true)),
+                write_if_changed: matches!(attrs.write_if_changed, Some(true)),
             }
         };
 
         let generics = Generics::new(&original.sig.generics);
 
-        let inputs = Inputs::new(original.sig.inputs.iter_mut(), attrs.init.is_some())?;
+        let inputs = Inputs::new(
+            original.sig.inputs.iter_mut(),
+            attrs.init.is_some(),
+            attrs.migrate.is_some(),
+        )?;
 
         if attrs.init.is_some() && inputs.receiver.is_some() {
             use syn::spanned::Spanned;
@@ -96,6 +127,26 @@ impl ImplItemMethodInfo {
             .into());
         };
 
+        if let (Some(_), Some(_)) = (&attrs.init, &attrs.migrate) {
+            return Err(syn::Error::new(
+                original.sig.ident.span(),
+                "A method can't be both init and migrate",
+            )
+            .into());
+        }
+
+        let old_state_ty = if let Some(migrate) = &attrs.migrate {
+            trait_item_method_info::check_migrate_inputs(
+                &inputs,
+                migrate,
+                original.sig.ident.span(),
+            )?;
+            // the old state is the first argument's type
+            Some(*inputs.old_state.as_ref().unwrap().ty.clone())
+        } else {
+            None
+        };
+
         if attrs.payable
             && !matches!(
                 inputs.receiver_kind,
@@ -121,8 +172,31 @@ impl ImplItemMethodInfo {
             .into());
         };
 
+        if attrs.write_if_changed
+            && !matches!(inputs.receiver_kind, super::inputs::ReceiverKind::RefMut)
+        {
+            use syn::spanned::Spanned;
+            return Err(syn::Error::new(
+                inputs.receiver.span(),
+                "Only methods based on `&mut self` can skip writing an unchanged state",
+            )
+            .into());
+        };
+
         let ret: syn::ReturnType = original.sig.output.clone();
 
+        let owned_return = if let super::inputs::ReceiverKind::Owned = inputs.receiver_kind {
+            use quote::ToTokens;
+            let self_ty = self_ty.to_token_stream().to_string();
+            // the trait's method already required `#[contract(split)]`
+            // for the returns that are split
+            Some(OwnedReturn::new(&ret, true, |ty| {
+                owned_return::is_ident(ty, "Self") || ty.to_token_stream().to_string() == self_ty
+            })?)
+        } else {
+            None
+        };
+
         Ok(Self {
             original: original.clone(),
             attrs,
@@ -131,6 +205,8 @@ impl ImplItemMethodInfo {
             generics,
             inputs,
             ret,
+            owned_return,
+            old_state_ty,
         })
     }
 }
@@ -9,6 +9,10 @@ pub struct Inputs {
     pub receiver: Option<syn::Receiver>,
     pub receiver_kind: ReceiverKind,
 
+    /// The first argument of migration methods, which receives
+    /// the old state.
+    pub old_state: Option<syn::PatType>,
+
     pub args: Vec<Arg>,
 }
 
@@ -19,12 +23,14 @@ pub enum ReceiverKind {
     Owned,
     Stateless,
     StatelessInit,
+    StatelessMigrate,
 }
 
 impl ReceiverKind {
-    pub fn from_syn(r: &Option<syn::Receiver>, init: bool) -> Self {
+    pub fn from_syn(r: &Option<syn::Receiver>, init: bool, migrate: bool) -> Self {
         match (init, r) {
             (true, _) => ReceiverKind::StatelessInit,
+            (false, _) if migrate => ReceiverKind::StatelessMigrate,
             (false, Some(r)) => match (r.reference.is_some(), r.mutability.is_some()) {
                 (true, true) => ReceiverKind::RefMut,
                 (true, false) => ReceiverKind::Ref,
@@ -45,6 +51,7 @@ impl ReceiverKind {
             ReceiverKind::Owned => quote!(_interface::ServeOwned),
             ReceiverKind::Stateless => quote!(_interface::ServeStateless),
             ReceiverKind::StatelessInit => quote!(_interface::ServeStatelessInit),
+            ReceiverKind::StatelessMigrate => quote!(_interface::ServeStatelessMigrate),
         }
     }
     pub fn quote_trait_link_str(&self) -> &str {
@@ -54,6 +61,21 @@ impl ReceiverKind {
             ReceiverKind::Owned => "[`ServeOwned`](_interface::ServeOwned)",
             ReceiverKind::Stateless => "[`ServeStateless`](_interface::ServeStateless)",
             ReceiverKind::StatelessInit => "[`ServeStatelessInit`](_interface::ServeStatelessInit)",
+            ReceiverKind::StatelessMigrate => {
+                "[`ServeStatelessMigrate`](_interface::ServeStatelessMigrate)"
+            }
+        }
+    }
+    /// The matching `_interface::ReceiverKind` value.
+    pub fn quote_kind(&self) -> proc_macro2::TokenStream {
+        use quote::quote;
+        match self {
+            ReceiverKind::RefMut => quote!(_interface::ReceiverKind::RefMut),
+            ReceiverKind::Ref => quote!(_interface::ReceiverKind::Ref),
+            ReceiverKind::Owned => quote!(_interface::ReceiverKind::Owned),
+            ReceiverKind::Stateless => quote!(_interface::ReceiverKind::Stateless),
+            ReceiverKind::StatelessInit => quote!(_interface::ReceiverKind::StatelessInit),
+            ReceiverKind::StatelessMigrate => quote!(_interface::ReceiverKind::StatelessMigrate),
         }
     }
     pub fn quote_self_argument(&self) -> proc_macro2::TokenStream {
@@ -64,6 +86,7 @@ impl ReceiverKind {
             ReceiverKind::Owned => quote!(Self::State,),
             ReceiverKind::Stateless => quote!(),
             ReceiverKind::StatelessInit => quote!(),
+            ReceiverKind::StatelessMigrate => quote!(Self::OldState,),
         }
     }
 }
@@ -74,6 +97,128 @@ pub struct Arg {
     pub attr: Attrs,
     pub arg: syn::PatType,
     pub fake_arg: Option<FakeArg>,
+    pub borrowed_arg: Option<BorrowedArg>,
+}
+
+impl Arg {
+    /// The argument as it's owned by clients, where references are
+    /// replaced by owned values.
+    pub fn owned_arg(&self) -> &syn::PatType {
+        match (&self.borrowed_arg, &self.fake_arg) {
+            (Some(borrowed), _) => &borrowed.owned_arg,
+            (None, Some(fake)) => &fake.fake_arg,
+            (None, None) => &self.arg,
+        }
+    }
+
+    /// The argument as it is in the `Args` structure, which may borrow
+    /// from the input for `'_args`.
+    pub fn args_field(&self) -> syn::PatType {
+        match &self.borrowed_arg {
+            Some(borrowed) => syn::PatType {
+                ty: Box::new(borrowed.args_ty.clone()),
+                ..borrowed.owned_arg.clone()
+            },
+            None => self.owned_arg().clone(),
+        }
+    }
+
+    /// The type of the argument's default value, which borrows for
+    /// `'static` instead of `'_args`.
+    pub fn default_ty(&self) -> &syn::Type {
+        match &self.borrowed_arg {
+            Some(borrowed) => &borrowed.static_ty,
+            None => &self.owned_arg().ty,
+        }
+    }
+}
+
+/// An argument that may be borrowed from the input, instead of being
+/// copied into an owned value.
+///
+/// Only `&str`, `&[u8]`, `Cow<str>` and `Cow<[u8]>` are borrowed, and
+/// they are all held as a `Cow` in the `Args` structure.
+/// Strings are borrowed from json unless they contain escapes, while
+/// json bytes are always owned, as they are sent as arrays. Raw bytes
+/// are always borrowed.
+#[derive(Debug)]
+pub struct BorrowedArg {
+    /// The type as it is in the `Args` structure.
+    /// eg. `x: &str` -> `x: Cow<'_args, str>`
+    pub args_ty: syn::Type,
+    /// The type borrowing for `'static`, for default values.
+    /// eg. `x: &str` -> `x: Cow<'static, str>`
+    pub static_ty: syn::Type,
+    /// The argument as it's owned by clients.
+    /// eg. `x: &str` -> `x: String`
+    pub owned_arg: syn::PatType,
+    /// Whether the field requires `#[serde(borrow)]`, which is
+    /// only the case for strings.
+    pub serde_borrow: bool,
+    /// Whether the method takes a reference, which is then re-borrowed
+    /// from the `Cow`, instead of taking the `Cow` itself.
+    pub reborrow: bool,
+}
+
+impl BorrowedArg {
+    pub fn new(pty: &syn::PatType) -> Option<Self> {
+        use syn::parse_quote;
+        // `str` or `[u8]`, with their owned type and whether
+        // they are a string
+        let borrowable = |ty: &syn::Type| -> Option<(syn::Type, bool)> {
+            match ty {
+                syn::Type::Path(p) if p.qself.is_none() && p.path.is_ident("str") => {
+                    Some((parse_quote!(String), true))
+                }
+                syn::Type::Slice(s) => match s.elem.as_ref() {
+                    syn::Type::Path(p) if p.qself.is_none() && p.path.is_ident("u8") => {
+                        Some((parse_quote!(Vec<u8>), false))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        };
+        let (ty, reborrow) = match pty.ty.as_ref() {
+            syn::Type::Reference(r) if r.mutability.is_none() => (r.elem.as_ref().clone(), true),
+            syn::Type::Path(p) if p.qself.is_none() => {
+                let last = p.path.segments.last()?;
+                if last.ident != "Cow" {
+                    return None;
+                }
+                let args = match &last.arguments {
+                    syn::PathArguments::AngleBracketed(args) => args,
+                    _ => return None,
+                };
+                let ty = args.args.iter().find_map(|a| match a {
+                    syn::GenericArgument::Type(ty) => Some(ty.clone()),
+                    _ => None,
+                })?;
+                (ty, false)
+            }
+            _ => return None,
+        };
+        let (owned_ty, is_str) = borrowable(&ty)?;
+
+        let mut owned_arg = pty.clone();
+        *owned_arg.ty = owned_ty;
+        Some(Self {
+            args_ty: parse_quote!(::std::borrow::Cow<'_args, #ty>),
+            static_ty: parse_quote!(::std::borrow::Cow<'static, #ty>),
+            owned_arg,
+            serde_borrow: is_str,
+            reborrow,
+        })
+    }
+
+    /// The value given to the method, from the `Args` field.
+    pub fn modify_pat(&self, pat: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.reborrow {
+            quote::quote!(&*#pat)
+        } else {
+            pat
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -129,12 +274,20 @@ pub struct RawAttrs {
     /// Forward attributes to be attached into the `Args` structure.
     #[darling(rename = "attr", default)]
     pub forward_attr: Option<syn::Meta>,
+
+    /// The value of the argument when it's missing from the input.
+    /// eg. `#[contract(default = "10")]`.
+    #[darling(default)]
+    pub default: Option<syn::LitStr>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Attrs {
     /// Forward attributes to be attached into the `Args` structure.
     pub forward_attr: Vec<syn::NestedMeta>,
+
+    /// The value of the argument when it's missing from the input.
+    pub default: Option<syn::Expr>,
 }
 
 fn append_modification(
@@ -292,8 +445,10 @@ impl Inputs {
     pub fn new<'a>(
         inputs: impl Iterator<Item = &'a mut syn::FnArg>,
         method_is_init: bool,
+        method_is_migrate: bool,
     ) -> error::Result<Self> {
         let mut receiver = None;
+        let mut old_state = None;
         let mut args = Vec::new();
         for arg in inputs {
             match arg {
@@ -301,6 +456,10 @@ impl Inputs {
                     assert!(receiver.is_none());
                     receiver = Some(r.clone())
                 }
+                // the old state is not part of the `Args`
+                syn::FnArg::Typed(pty) if method_is_migrate && old_state.is_none() => {
+                    old_state = Some(pty.clone());
+                }
                 syn::FnArg::Typed(pty) => {
                     let (contract_attr, non_contract_attr) =
                         meta_attrs::partition_attrs(&pty.attrs, "contract");
@@ -319,16 +478,20 @@ impl Inputs {
                         // eg. #[contract(attr(.., ..))]
                         let attrs = RawAttrs::from_list(&nested)?;
 
-                        let attrs = if let Some(fa) = attrs.forward_attr {
-                            let nested = meta_attrs::remove_first_layer(vec![fa], "attr")?;
-
-                            Attrs {
-                                forward_attr: nested,
-                            }
+                        let forward_attr = if let Some(fa) = attrs.forward_attr {
+                            meta_attrs::remove_first_layer(vec![fa], "attr")?
                         } else {
-                            Attrs {
-                                forward_attr: vec![],
-                            }
+                            vec![]
+                        };
+                        let default = attrs
+                            .default
+                            .as_ref()
+                            .map(|expr| expr.parse::<syn::Expr>())
+                            .transpose()?;
+
+                        let attrs = Attrs {
+                            forward_attr,
+                            default,
                         };
 
                         // let nested = &attrs.forward_attr;
@@ -338,21 +501,26 @@ impl Inputs {
                     };
 
                     let mut fake_arg = None;
-                    let mut modifications = None;
+                    let borrowed_arg = BorrowedArg::new(pty);
                     let arg = pty.clone();
-                    let mut pty = pty.clone();
-                    adapt_argument(&mut pty, &mut modifications)?;
-                    if let Some(m) = modifications {
-                        fake_arg = Some(FakeArg {
-                            fake_arg: pty.clone(),
-                            modification: *m,
-                        });
+                    // borrowed args are re-borrowed from their `Cow`
+                    if borrowed_arg.is_none() {
+                        let mut modifications = None;
+                        let mut pty = pty.clone();
+                        adapt_argument(&mut pty, &mut modifications)?;
+                        if let Some(m) = modifications {
+                            fake_arg = Some(FakeArg {
+                                fake_arg: pty.clone(),
+                                modification: *m,
+                            });
+                        }
                     }
 
                     let arg = Arg {
                         contract_attr,
                         arg,
                         fake_arg,
+                        borrowed_arg,
                         attr,
                     };
 
@@ -360,16 +528,26 @@ impl Inputs {
                 }
             }
         }
-        let receiver_kind = ReceiverKind::from_syn(&receiver, method_is_init);
+        let receiver_kind = ReceiverKind::from_syn(&receiver, method_is_init, method_is_migrate);
 
         Ok(Self {
             receiver,
             receiver_kind,
+            old_state,
             args,
         })
     }
 
+    /// Whether any argument is borrowed from the input, in which case
+    /// the `Args` structure has a `'_args` lifetime.
+    pub fn has_borrowed_args(&self) -> bool {
+        self.args.iter().any(|a| a.borrowed_arg.is_some())
+    }
+
     pub fn replace_from_self_to_state(mut self) -> Self {
+        if let Some(old_state) = self.old_state.as_mut() {
+            replace_ident_from_self_to_state(old_state);
+        }
         for pty in self.args.iter_mut() {
             replace_ident_from_self_to_state(&mut pty.arg);
         }
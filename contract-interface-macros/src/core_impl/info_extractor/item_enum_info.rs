@@ -0,0 +1,115 @@
+use super::event_attr::EventAttr;
+use super::meta_attrs;
+use crate::error;
+use darling::FromMeta;
+use inflector::Inflector;
+
+/// Information extracted from `ItemEnum`.
+pub struct ItemEnumInfo {
+    /// The original AST of the enum.
+    pub original: syn::ItemEnum,
+
+    pub attrs: Attrs,
+    pub doc_attrs: Vec<syn::Attribute>,
+    pub non_contract_attrs: Vec<syn::Attribute>,
+
+    /// The enum name.
+    /// eg. `enum Name`
+    pub original_ident: syn::Ident,
+
+    /// Each variant is an event.
+    pub events: Vec<EventVariant>,
+}
+
+/// An event, as a variant of the enum.
+pub struct EventVariant {
+    /// The variant name.
+    pub ident: syn::Ident,
+
+    /// The event name, as it is logged.
+    /// eg. `FtMint` is logged as `"ft_mint"`.
+    pub name: String,
+
+    /// The type of the event's data, if the variant has any.
+    pub data: Option<syn::Type>,
+}
+
+#[derive(Debug, FromMeta)]
+pub struct RawAttrs {
+    /// The name that will be used for the module that will contain
+    /// the generated items.
+    #[darling(default, rename = "mod")]
+    module_name: Option<syn::Ident>,
+
+    /// The standard and version of the events.
+    event: EventAttr,
+}
+
+#[derive(Debug)]
+pub struct Attrs {
+    /// The name that will be used for the module that will contain
+    /// the generated items.
+    pub module_name: syn::Ident,
+
+    /// The standard and version of the events.
+    pub event: EventAttr,
+}
+
+impl ItemEnumInfo {
+    pub(crate) fn new(
+        original: &mut syn::ItemEnum,
+        attr_args: syn::AttributeArgs,
+    ) -> error::Result<Self> {
+        use syn::spanned::Spanned;
+        let original_ident = original.ident.clone();
+
+        let (raw_attrs, non_contract_attrs) =
+            meta_attrs::meta_attrs::<RawAttrs>(&original.attrs, attr_args, "contract")?;
+        let (doc_attrs, non_contract_attrs) =
+            meta_attrs::partition_attrs(&non_contract_attrs, "doc");
+
+        let attrs = Attrs {
+            module_name: raw_attrs.module_name.unwrap_or_else(|| {
+                let res = original.ident.to_string().to_snake_case();
+                syn::Ident::new(&res, proc_macro2::Span::call_site())
+            }),
+            event: raw_attrs.event,
+        };
+
+        if !original.generics.params.is_empty() {
+            return Err(
+                syn::Error::new(original.generics.span(), "Events can't have generics").into(),
+            );
+        }
+
+        let mut events = vec![];
+        for variant in &original.variants {
+            let data =
+                match &variant.fields {
+                    syn::Fields::Unit => None,
+                    syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        Some(fields.unnamed[0].ty.clone())
+                    }
+                    _ => return Err(syn::Error::new(
+                        variant.fields.span(),
+                        "Events must either have no data or a single unnamed field with the data",
+                    )
+                    .into()),
+                };
+            events.push(EventVariant {
+                ident: variant.ident.clone(),
+                name: variant.ident.to_string().to_snake_case(),
+                data,
+            });
+        }
+
+        Ok(Self {
+            original: original.clone(),
+            attrs,
+            doc_attrs,
+            non_contract_attrs,
+            original_ident,
+            events,
+        })
+    }
+}
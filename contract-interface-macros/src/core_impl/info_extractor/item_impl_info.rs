@@ -57,6 +57,12 @@ pub struct RawAttrs {
     /// wasm file.
     #[darling(default)]
     pub serve: Option<bool>,
+
+    /// Supertraits whose methods are also served by the generated
+    /// `extern_*!` macro.
+    /// eg. `#[contract(supertrait(extern_macro = "crate::macros::extern_impl_a", impl_mod = "crate::impl_a"))]`.
+    #[darling(default, multiple, rename = "supertrait")]
+    pub supertraits: Vec<SupertraitAttr>,
     // /// Whether this struct/trait's methods should potentially be
     // /// callable by the generated wasm.
     // ///
@@ -92,6 +98,39 @@ pub struct Attrs {
     /// requests to this struct/trait's methods of your deployed
     /// wasm file.
     pub serve: bool,
+
+    /// Supertraits whose methods are also served by the generated
+    /// `extern_*!` macro.
+    pub supertraits: Vec<SupertraitAttr>,
+}
+
+/// A supertrait implementation that is served together with this one.
+///
+/// Both paths must be absolute (eg. starting with `crate::`),
+/// since they are used wherever the `extern_*!` macro is called.
+///
+/// The supertrait must be a bound of the trait, otherwise it fails to
+/// compile. It's implementation must have the same generics as this one,
+/// which are forwarded to it's `extern_*!` macro.
+///
+/// Each implementation can only be served once per prefix, otherwise it's
+/// methods are defined twice and it fails to compile. So when two
+/// supertraits share a supertrait of their own (a diamond), one of them
+/// must be set with `skip_supertraits`.
+#[derive(Debug, Clone, FromMeta)]
+pub struct SupertraitAttr {
+    /// The `extern_*!` macro generated for the supertrait's impl.
+    pub extern_macro: syn::Path,
+
+    /// The module generated for the supertrait's impl.
+    pub impl_mod: syn::Path,
+
+    /// Whether the supertrait's own supertraits are not served by
+    /// this implementation, as they are already served by another
+    /// supertrait.
+    /// eg. `#[contract(supertrait(extern_macro = "..", impl_mod = "..", skip_supertraits))]`.
+    #[darling(default)]
+    pub skip_supertraits: bool,
 }
 
 #[derive(Debug)]
@@ -108,7 +147,7 @@ pub struct ImplItems {
 }
 
 impl ImplItems {
-    pub fn get_items(items: &mut [syn::ImplItem]) -> error::Result<Self> {
+    pub fn get_items(items: &mut [syn::ImplItem], self_ty: &syn::Type) -> error::Result<Self> {
         let consts = items
             .iter()
             .filter_map(|item| {
@@ -142,7 +181,12 @@ impl ImplItems {
                     None
                 }
             })
-            .map(|tim| Ok((tim.sig.ident.clone(), ImplItemMethodInfo::new(tim)?)))
+            .map(|tim| {
+                Ok((
+                    tim.sig.ident.clone(),
+                    ImplItemMethodInfo::new(tim, self_ty)?,
+                ))
+            })
             .collect::<Result<_, error::Error>>()?;
 
         Ok(Self {
@@ -165,6 +209,7 @@ impl ItemImplInfo {
             module_name: attrs.module_name,
             trait_mod_path: attrs.trait_mod_path,
             serve: matches!(attrs.serve, Some(true) | None),
+            supertraits: attrs.supertraits,
         };
 
         let generics = Generics::new(&original.generics);
@@ -172,7 +217,7 @@ impl ItemImplInfo {
         let self_ty = (*original.self_ty.as_ref()).clone();
         let trait_path = original.trait_.as_ref().map(|(_, p, _)| p);
 
-        let items = ImplItems::get_items(&mut original.items)?;
+        let items = ImplItems::get_items(&mut original.items, &self_ty)?;
 
         Ok(Self {
             original: original.clone(),
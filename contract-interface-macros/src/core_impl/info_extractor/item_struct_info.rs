@@ -0,0 +1,199 @@
+use super::meta_attrs;
+use crate::error;
+use darling::FromMeta;
+use inflector::Inflector;
+
+/// Information extracted from `ItemStruct`.
+pub struct ItemStructInfo {
+    /// The original AST of the struct.
+    pub original: syn::ItemStruct,
+
+    pub attrs: Attrs,
+    pub doc_attrs: Vec<syn::Attribute>,
+    pub non_contract_attrs: Vec<syn::Attribute>,
+
+    /// The struct name.
+    /// eg. `struct Name`
+    pub original_ident: syn::Ident,
+
+    /// The versions of the state, from the oldest to the latest,
+    /// together with the name of their enum variant.
+    /// The last one is the struct itself.
+    ///
+    /// Empty if the state is not versioned.
+    pub versions: Vec<(syn::Ident, syn::Path)>,
+
+    /// The fields of the state that are stored under their own key.
+    ///
+    /// Empty if the state doesn't have lazy fields.
+    pub lazy_fields: Vec<syn::Field>,
+
+    /// Whether the struct is not a state, but a type used by methods
+    /// that should derive `JsonSchema` with the `schemars` feature.
+    pub json_schema: bool,
+}
+
+#[derive(Debug, FromMeta)]
+pub struct RawAttrs {
+    /// The name that will be used for the module that will contain
+    /// the generated items.
+    #[darling(default, rename = "mod")]
+    module_name: Option<syn::Ident>,
+
+    /// The versions of the state, from the oldest to the latest.
+    /// eg. `#[contract(state_versions(V1, V2, Current))]`.
+    #[darling(default)]
+    state_versions: darling::util::PathList,
+
+    /// Whether each field of the state is stored under it's own key.
+    /// eg. `#[contract(lazy_fields)]`.
+    #[darling(default)]
+    lazy_fields: bool,
+
+    /// Whether the struct should derive `JsonSchema` with the `schemars`
+    /// feature, such as for the types of arguments or returned values.
+    /// eg. `#[contract(json_schema)]`.
+    #[darling(default)]
+    json_schema: bool,
+}
+
+#[derive(Debug)]
+pub struct Attrs {
+    /// The name that will be used for the module that will contain
+    /// the generated items.
+    pub module_name: syn::Ident,
+}
+
+impl ItemStructInfo {
+    pub(crate) fn new(
+        original: &mut syn::ItemStruct,
+        attr_args: syn::AttributeArgs,
+    ) -> error::Result<Self> {
+        use syn::spanned::Spanned;
+        let original_ident = original.ident.clone();
+
+        let (raw_attrs, non_contract_attrs) =
+            meta_attrs::meta_attrs::<RawAttrs>(&original.attrs, attr_args, "contract")?;
+        let (doc_attrs, non_contract_attrs) =
+            meta_attrs::partition_attrs(&non_contract_attrs, "doc");
+
+        let attrs = Attrs {
+            module_name: raw_attrs.module_name.unwrap_or_else(|| {
+                let res = original.ident.to_string().to_snake_case();
+                syn::Ident::new(&res, proc_macro2::Span::call_site())
+            }),
+        };
+
+        if raw_attrs.json_schema {
+            if !raw_attrs.state_versions.is_empty() || raw_attrs.lazy_fields {
+                return Err(syn::Error::new(
+                    original_ident.span(),
+                    "`json_schema` is for types used by methods, not for contract states",
+                )
+                .into());
+            }
+            return Ok(Self {
+                original: original.clone(),
+                attrs,
+                doc_attrs,
+                non_contract_attrs,
+                original_ident,
+                versions: vec![],
+                lazy_fields: vec![],
+                json_schema: true,
+            });
+        }
+
+        if !original.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                original.generics.span(),
+                "Contract states can't have generics",
+            )
+            .into());
+        }
+
+        match (raw_attrs.state_versions.is_empty(), raw_attrs.lazy_fields) {
+            (true, false) => {
+                return Err(syn::Error::new(
+                    original_ident.span(),
+                    "Contract states require either `state_versions(..)` or `lazy_fields`, and other structs require `json_schema`",
+                )
+                .into())
+            }
+            (false, true) => {
+                return Err(syn::Error::new(
+                    original_ident.span(),
+                    "Versioned states can't have lazy fields",
+                )
+                .into())
+            }
+            _ => {}
+        }
+
+        let versions = if raw_attrs.state_versions.is_empty() {
+            vec![]
+        } else {
+            Self::versions(&original_ident, raw_attrs.state_versions.to_vec())?
+        };
+
+        let lazy_fields = if raw_attrs.lazy_fields {
+            match &original.fields {
+                syn::Fields::Named(fields) => fields.named.iter().cloned().collect(),
+                _ => {
+                    return Err(syn::Error::new(
+                        original.fields.span(),
+                        "Lazy fields require a struct with named fields",
+                    )
+                    .into())
+                }
+            }
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            original: original.clone(),
+            attrs,
+            doc_attrs,
+            non_contract_attrs,
+            original_ident,
+            versions,
+            lazy_fields,
+            json_schema: false,
+        })
+    }
+
+    /// Checks the state versions and names their enum variants.
+    fn versions(
+        original_ident: &syn::Ident,
+        paths: Vec<syn::Path>,
+    ) -> error::Result<Vec<(syn::Ident, syn::Path)>> {
+        use syn::spanned::Spanned;
+
+        match paths.last() {
+            Some(last) if last.is_ident(original_ident) => {}
+            _ => {
+                return Err(syn::Error::new(
+                    original_ident.span(),
+                    "The last state version must be the struct itself",
+                )
+                .into())
+            }
+        }
+
+        let mut versions: Vec<(syn::Ident, syn::Path)> = vec![];
+        for path in paths {
+            // the path has at least the struct's ident
+            let variant = path.segments.last().unwrap().ident.clone();
+            if versions.iter().any(|(v, _)| v == &variant) {
+                return Err(syn::Error::new(
+                    path.span(),
+                    "State versions must have distinct names",
+                )
+                .into());
+            }
+            versions.push((variant, path));
+        }
+        Ok(versions)
+    }
+}
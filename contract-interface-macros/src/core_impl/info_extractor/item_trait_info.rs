@@ -2,7 +2,7 @@ use super::item_generics::Generics;
 use super::meta_attrs;
 use super::trait_item_method_info::TraitItemMethodInfo;
 use crate::error;
-use crate::replace_ident::replace_ident_from_self_to_state;
+use crate::replace_ident::{mentions_ident, replace_ident_from_self_to_state};
 use darling::FromMeta;
 use inflector::Inflector;
 
@@ -55,6 +55,20 @@ pub struct RawAttrs {
     /// contract that is serving this trait's methods.
     #[darling(default)]
     request: bool,
+
+    /// The name of the standard that this trait implements.
+    /// eg. `#[contract(standard = "nep141", version = "1.0.0")]`.
+    #[darling(default)]
+    standard: Option<String>,
+
+    /// The version of the standard that this trait implements.
+    #[darling(default)]
+    version: Option<String>,
+
+    /// Whether the json args of every method reject unknown fields.
+    /// eg. `#[contract(strict)]`.
+    #[darling(default)]
+    strict: bool,
 }
 
 #[derive(Debug)]
@@ -62,6 +76,12 @@ pub struct Attrs {
     /// The name that will be used for the module that will contain
     /// the generated items.
     pub module_name: syn::Ident,
+
+    /// The name and version of the standard that this trait implements.
+    pub standard: Option<(String, String)>,
+
+    /// Whether the json args of every method reject unknown fields.
+    pub strict: bool,
 }
 
 pub struct TraitItems {
@@ -162,6 +182,18 @@ impl ItemTraitInfo {
                 let res = original.ident.to_string().to_snake_case();
                 syn::Ident::new(&res, proc_macro2::Span::call_site())
             }),
+            standard: match (raw_attrs.standard, raw_attrs.version) {
+                (Some(standard), Some(version)) => Some((standard, version)),
+                (None, None) => None,
+                _ => {
+                    return Err(syn::Error::new(
+                        original_ident.span(),
+                        "Both the `standard` and it's `version` must be set",
+                    )
+                    .into())
+                }
+            },
+            strict: raw_attrs.strict,
         };
 
         let generics = Generics::new(&original.generics).replace_from_self_to_state();
@@ -195,6 +227,41 @@ impl ItemTraitInfo {
 
         let items = TraitItems::replace_from_self_to_state(&mut original.items)?;
 
+        // default values are made by functions that are not generic
+        for method in items.methods.values() {
+            let generic_names = generics
+                .lifetimes
+                .keys()
+                .chain(method.generics.lifetimes.keys())
+                .map(|l| l.ident.to_string())
+                .chain(
+                    generics
+                        .types
+                        .keys()
+                        .chain(generics.consts.keys())
+                        .chain(method.generics.types.keys())
+                        .chain(method.generics.consts.keys())
+                        .map(|i| i.to_string()),
+                )
+                .chain(std::iter::once("_State".to_string()))
+                .collect::<Vec<_>>();
+            for arg in method.inputs.args.iter() {
+                if arg.attr.default.is_none() {
+                    continue;
+                }
+                let ty = &arg.owned_arg().ty;
+                let ty = quote::quote!(#ty);
+                if generic_names.iter().any(|name| mentions_ident(&ty, name)) {
+                    use syn::spanned::Spanned;
+                    return Err(syn::Error::new(
+                        arg.arg.span(),
+                        "Only arguments with non-generic types can have default values",
+                    )
+                    .into());
+                }
+            }
+        }
+
         Ok(Self {
             original_ident,
             attrs,
@@ -0,0 +1,31 @@
+use darling::FromMeta;
+
+/// Attributes for methods that migrate the state.
+///
+/// eg. `#[contract(migrate)]`.
+/// eg. `#[contract(migrate(from = "OldState"))]`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrateAttr {
+    /// The type of the old state, as it is read from the storage.
+    ///
+    /// The type of the method's first argument is always used, and this
+    /// must be the same type if set.
+    pub from: Option<syn::Path>,
+}
+
+#[derive(Debug, FromMeta)]
+struct RawMigrateAttr {
+    #[darling(default)]
+    from: Option<syn::Path>,
+}
+
+impl FromMeta for MigrateAttr {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        let raw = RawMigrateAttr::from_list(items)?;
+        Ok(Self { from: raw.from })
+    }
+}
@@ -2,8 +2,15 @@ pub(crate) mod inputs;
 pub(crate) mod item_generics;
 pub(crate) mod meta_attrs;
 
+pub(crate) mod event_attr;
+pub(crate) mod format_attr;
+pub(crate) mod handle_result_attr;
 pub(crate) mod impl_item_method_info;
 pub(crate) mod init_attr;
+pub(crate) mod item_enum_info;
 pub(crate) mod item_impl_info;
+pub(crate) mod item_struct_info;
 pub(crate) mod item_trait_info;
+pub(crate) mod migrate_attr;
+pub(crate) mod owned_return;
 pub(crate) mod trait_item_method_info;
@@ -0,0 +1,71 @@
+use crate::error;
+
+/// How the value returned by an owned-`self` method is split into
+/// the new state and the value that is returned to the caller.
+#[derive(Debug, Clone)]
+pub enum OwnedReturn {
+    /// The method only returns the new state.
+    /// eg. `fn f(self) -> Self`.
+    StateOnly,
+    /// The method returns the new state and a value.
+    /// eg. `fn f(self) -> (Self, T)`.
+    Pair(syn::Type),
+    /// The method returns a designated type that implements
+    /// `SplitState`, which requires `#[contract(split)]`.
+    /// eg. `fn f(self) -> R`.
+    Split(syn::Type),
+}
+
+impl OwnedReturn {
+    /// Classifies the return of an owned-`self` method,
+    /// where `is_state` tells whether a type is the state itself
+    /// (eg. `Self` or `_State`).
+    ///
+    /// Any other type is only accepted if `split` is set, as it's
+    /// otherwise likely a mistake, such as `fn f(self) -> T`.
+    pub fn new(
+        ret: &syn::ReturnType,
+        split: bool,
+        is_state: impl Fn(&syn::Type) -> bool,
+    ) -> error::Result<Self> {
+        use syn::spanned::Spanned;
+        let ty = match ret {
+            syn::ReturnType::Default => {
+                return Err(syn::Error::new(
+                    ret.span(),
+                    "Owned methods must return the new state, eg. `Self` or `(Self, T)`",
+                )
+                .into())
+            }
+            syn::ReturnType::Type(_, ty) => ty.as_ref(),
+        };
+        if is_state(ty) {
+            return Ok(OwnedReturn::StateOnly);
+        }
+        if let syn::Type::Tuple(t) = ty {
+            if t.elems.len() == 2 && is_state(&t.elems[0]) {
+                return Ok(OwnedReturn::Pair(t.elems[1].clone()));
+            }
+        }
+        if !split {
+            return Err(syn::Error::new(
+                ty.span(),
+                "Owned methods must return the new state, eg. `Self` or `(Self, T)`, \
+                or a type that implements `SplitState` with `#[contract(split)]`",
+            )
+            .into());
+        }
+        Ok(OwnedReturn::Split(ty.clone()))
+    }
+}
+
+/// Whether the type is exactly the given identifier.
+/// eg. `Self` or `_State`.
+pub fn is_ident(ty: &syn::Type, ident: &str) -> bool {
+    match ty {
+        syn::Type::Path(p) if p.qself.is_none() => p.path.is_ident(ident),
+        syn::Type::Paren(p) => is_ident(&p.elem, ident),
+        syn::Type::Group(g) => is_ident(&g.elem, ident),
+        _ => false,
+    }
+}
@@ -1,9 +1,13 @@
+use super::format_attr::FormatAttr;
+use super::handle_result_attr::{self, HandleResultAttr};
 use super::init_attr;
-use super::inputs::Inputs;
+use super::inputs::{Inputs, ReceiverKind};
 use super::item_generics::Generics;
 use super::meta_attrs;
+use super::migrate_attr::MigrateAttr;
+use super::owned_return::{self, OwnedReturn};
 use crate::error;
-use crate::replace_ident::replace_ident_from_self_to_state;
+use crate::replace_ident::{mentions_state, replace_ident_from_self_to_state};
 use darling::FromMeta;
 
 /// Information extracted from trait method.
@@ -21,6 +25,20 @@ pub struct TraitItemMethodInfo {
     pub inputs: Inputs,
 
     pub ret: syn::ReturnType,
+
+    /// How the return is split into the new state and the returned
+    /// value, for owned-`self` methods.
+    pub owned_return: Option<OwnedReturn>,
+
+    /// The `T` and `E` of the returned `Result<T, E>`, for methods
+    /// with `#[contract(handle_result)]`.
+    pub result_types: Option<(syn::Type, syn::Type)>,
+
+    /// Whether the method's generics and arguments don't mention `Self`,
+    /// not counting bounds directly on `Self`, such as `Self: Sized`.
+    ///
+    /// The request builders of such methods are not generic over `_State`.
+    pub self_free: bool,
 }
 
 #[derive(Debug, FromMeta)]
@@ -33,6 +51,53 @@ pub struct RawAttrs {
     #[darling(default)]
     pub init: Option<init_attr::InitAttr>,
 
+    /// Whether the method migrates an old state into a new one.
+    /// eg. `#[contract(migrate)]`.
+    #[darling(default)]
+    pub migrate: Option<MigrateAttr>,
+
+    /// The serialization format of the `Args` structure.
+    /// eg. `#[contract(args_format = "raw")]`.
+    #[darling(default)]
+    pub args_format: Option<FormatAttr>,
+
+    /// The serialization format of the `Return` structure.
+    /// eg. `#[contract(return_format = "borsh")]`.
+    #[darling(default)]
+    pub return_format: Option<FormatAttr>,
+
+    /// Whether the json args reject unknown fields.
+    /// eg. `#[contract(strict)]`.
+    #[darling(default)]
+    pub strict: bool,
+
+    /// Whether the value returned by an owned-`self` method is a
+    /// designated type that implements `SplitState`.
+    /// eg. `#[contract(split)]`.
+    #[darling(default)]
+    pub split: bool,
+
+    /// Whether the returned `Result` should make the method fail
+    /// on `Err` values.
+    /// eg. `#[contract(handle_result(error = "json"))]`.
+    #[darling(default)]
+    pub handle_result: Option<HandleResultAttr>,
+
+    /// The events that the method may emit.
+    /// eg. `#[contract(emits(FtEvent))]`.
+    #[darling(default)]
+    pub emits: darling::util::PathList,
+
+    /// The gas that clients should attach by default.
+    /// eg. `#[contract(default_gas = 30_000_000_000_000)]`.
+    #[darling(default)]
+    pub default_gas: Option<syn::LitInt>,
+
+    /// The deposit, in yoctoNEAR, that clients should attach by default.
+    /// eg. `#[contract(default_deposit = 1)]`.
+    #[darling(default)]
+    pub default_deposit: Option<syn::LitInt>,
+
     /// Forward attributes to be attached into the `Args` structure.
     #[darling(default)]
     pub args_attr: Option<syn::Meta>,
@@ -50,6 +115,35 @@ pub struct Attrs {
 
     pub init: Option<init_attr::InitAttr>,
 
+    /// Whether the method migrates an old state into a new one.
+    pub migrate: Option<MigrateAttr>,
+
+    /// The serialization format of the `Args` structure.
+    pub args_format: FormatAttr,
+
+    /// The serialization format of the `Return` structure.
+    pub return_format: FormatAttr,
+
+    /// Whether the json args reject unknown fields.
+    pub strict: bool,
+
+    /// Whether the value returned by an owned-`self` method is a
+    /// designated type that implements `SplitState`.
+    pub split: bool,
+
+    /// Whether the returned `Result` should make the method fail
+    /// on `Err` values.
+    pub handle_result: Option<HandleResultAttr>,
+
+    /// The events that the method may emit.
+    pub emits: Vec<syn::Path>,
+
+    /// The gas that clients should attach by default.
+    pub default_gas: Option<u64>,
+
+    /// The deposit, in yoctoNEAR, that clients should attach by default.
+    pub default_deposit: Option<u128>,
+
     // TODO: use value on code gen
     /// Forward attributes to be attached into the `Args` structure.
     args_attr: Vec<syn::NestedMeta>,
@@ -73,6 +167,80 @@ impl TraitItemMethodInfo {
 
             let attrs = RawAttrs::from_list(&nested)?;
 
+            if let (Some(_), Some(_)) = (&attrs.init, &attrs.return_format) {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "Init methods always return the state serialized with borsh",
+                )
+                .into());
+            }
+
+            if let (Some(_), Some(_)) = (&attrs.migrate, &attrs.return_format) {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "Migrate methods always return the state serialized with borsh",
+                )
+                .into());
+            }
+
+            if let (Some(_), Some(_)) = (&attrs.init, &attrs.migrate) {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "A method can't be both init and migrate",
+                )
+                .into());
+            }
+
+            if let Some(FormatAttr::Borsh) = attrs.args_format {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "Only json and raw args are supported",
+                )
+                .into());
+            }
+
+            if let Some(FormatAttr::JsonPositional) = attrs.return_format {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "Only json, borsh and raw returns are supported",
+                )
+                .into());
+            }
+
+            if let (Some(FormatAttr::Raw | FormatAttr::JsonPositional), true) =
+                (&attrs.args_format, attrs.strict)
+            {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "Only methods with json args can be strict",
+                )
+                .into());
+            }
+
+            // init and migrate methods return the state itself
+            let return_format = if attrs.init.is_some() || attrs.migrate.is_some() {
+                FormatAttr::Borsh
+            } else {
+                attrs.return_format.unwrap_or_default()
+            };
+
+            let default_gas = attrs
+                .default_gas
+                .as_ref()
+                .map(|gas| gas.base10_parse::<u64>())
+                .transpose()?;
+            let default_deposit = attrs
+                .default_deposit
+                .as_ref()
+                .map(|deposit| deposit.base10_parse::<u128>())
+                .transpose()?;
+
             let args_attr = if let Some(fa) = attrs.args_attr {
                 meta_attrs::remove_first_layer(vec![fa], "args_attr")?
             } else {
@@ -90,6 +258,15 @@ impl TraitItemMethodInfo {
                     syn::Ident::new(&res, proc_macro2::Span::call_site())
                 }),
                 init: attrs.init,
+                migrate: attrs.migrate,
+                args_format: attrs.args_format.unwrap_or_default(),
+                return_format,
+                strict: attrs.strict,
+                split: attrs.split,
+                handle_result: attrs.handle_result,
+                emits: attrs.emits.to_vec(),
+                default_gas,
+                default_deposit,
                 args_attr,
                 return_attr,
             }
@@ -97,8 +274,26 @@ impl TraitItemMethodInfo {
 
         let generics = Generics::new(&original.sig.generics).replace_from_self_to_state();
 
-        let inputs = Inputs::new(original.sig.inputs.iter_mut(), attrs.init.is_some())?
-            .replace_from_self_to_state();
+        let inputs = Inputs::new(
+            original.sig.inputs.iter_mut(),
+            attrs.init.is_some(),
+            attrs.migrate.is_some(),
+        )?
+        .replace_from_self_to_state();
+
+        let self_free = {
+            let types = generics.types.values();
+            let type_bounds = generics.type_bounds.values().filter(|pt| {
+                let ty = &pt.bounded_ty;
+                quote::quote!(#ty).to_string() != "_State"
+            });
+            let arg_types = inputs.args.iter().map(|a| &a.owned_arg().ty);
+            !mentions_state(&quote::quote! {
+                #(#types)*
+                #(#type_bounds)*
+                #(#arg_types)*
+            })
+        };
 
         if attrs.init.is_some() && inputs.receiver.is_some() {
             use syn::spanned::Spanned;
@@ -109,9 +304,89 @@ impl TraitItemMethodInfo {
             .into());
         };
 
+        if attrs.args_format != FormatAttr::Json {
+            if let Some(arg) = inputs.args.iter().find(|a| a.attr.default.is_some()) {
+                use syn::spanned::Spanned;
+                return Err(syn::Error::new(
+                    arg.arg.span(),
+                    "Only methods with json args can have default values",
+                )
+                .into());
+            }
+        };
+
+        if attrs.args_format == FormatAttr::JsonPositional && inputs.args.len() > 12 {
+            use syn::spanned::Spanned;
+            return Err(syn::Error::new(
+                original.sig.span(),
+                "Methods with json_positional args can have at most 12 arguments",
+            )
+            .into());
+        };
+
+        if attrs.args_format == FormatAttr::Raw && inputs.args.len() != 1 {
+            use syn::spanned::Spanned;
+            return Err(syn::Error::new(
+                original.sig.span(),
+                "Methods with raw args must have a single argument, which receives the input bytes",
+            )
+            .into());
+        };
+
+        if let Some(migrate) = &attrs.migrate {
+            check_migrate_inputs(&inputs, migrate, original.sig.ident.span())?;
+        };
+
         let mut ret: syn::ReturnType = original.sig.output.clone();
         replace_ident_from_self_to_state(&mut ret);
 
+        let owned_return = if let ReceiverKind::Owned = inputs.receiver_kind {
+            Some(OwnedReturn::new(&ret, attrs.split, |ty| {
+                owned_return::is_ident(ty, "_State")
+            })?)
+        } else {
+            None
+        };
+
+        if attrs.split && !matches!(owned_return, Some(OwnedReturn::Split(_))) {
+            use syn::spanned::Spanned;
+            return Err(syn::Error::new(
+                original.sig.span(),
+                "Only owned methods that don't return `Self` or `(Self, T)` can be split",
+            )
+            .into());
+        };
+
+        let result_types = if attrs.handle_result.is_some() {
+            use syn::spanned::Spanned;
+            if !matches!(
+                inputs.receiver_kind,
+                ReceiverKind::RefMut | ReceiverKind::Ref | ReceiverKind::Stateless
+            ) {
+                return Err(syn::Error::new(
+                    original.sig.span(),
+                    "Only methods based on `&mut self`, `&self` or no `self` can handle a `Result`",
+                )
+                .into());
+            }
+            let result_types = match &ret {
+                syn::ReturnType::Type(_, ty) => handle_result_attr::result_types(ty),
+                syn::ReturnType::Default => None,
+            };
+            match result_types {
+                Some((ok, err)) => Some((ok.clone(), err.clone())),
+                None => {
+                    return Err(syn::Error::new(
+                        ret.span(),
+                        "Methods that handle a `Result` must return a `Result<T, E>`",
+                    )
+                    .into())
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(Self {
             original: original.clone(),
             attrs,
@@ -120,6 +395,51 @@ impl TraitItemMethodInfo {
             generics,
             inputs,
             ret,
+            owned_return,
+            result_types,
+            self_free,
         })
     }
 }
+
+/// Migrate methods must receive the old state as their first argument,
+/// and can't have a `self` parameter.
+///
+/// If `#[contract(migrate(from = "OldState"))]` is set, it must be the
+/// same as the type of the first argument.
+pub fn check_migrate_inputs(
+    inputs: &Inputs,
+    migrate: &MigrateAttr,
+    span: proc_macro2::Span,
+) -> error::Result<()> {
+    use syn::spanned::Spanned;
+    if inputs.receiver.is_some() {
+        return Err(syn::Error::new(
+            inputs.receiver.span(),
+            "Migrate methods can't have `self` parameter as the old state is given as the first argument",
+        )
+        .into());
+    };
+    if inputs.old_state.is_none() {
+        return Err(syn::Error::new(
+            span,
+            "Migrate methods must receive the old state as their first argument",
+        )
+        .into());
+    };
+    if let (Some(from), Some(old_state)) = (&migrate.from, &inputs.old_state) {
+        let old_state_ty = &old_state.ty;
+        let old_state_ty = quote::quote!(#old_state_ty).to_string();
+        if quote::quote!(#from).to_string() != old_state_ty {
+            return Err(syn::Error::new(
+                from.span(),
+                format!(
+                    "The old state must be the same as the type of the first argument, `{}`",
+                    old_state_ty
+                ),
+            )
+            .into());
+        }
+    };
+    Ok(())
+}
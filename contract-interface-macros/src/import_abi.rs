@@ -0,0 +1,608 @@
+//! Generates a `#[contract]` trait, and the types that it uses, from a
+//! [NEAR ABI](https://github.com/near/abi) document, so that contracts
+//! that were not written with this crate can be called with the
+//! trait's `request::Request` builders or it's `Client`.
+
+use crate::error;
+use darling::FromMeta;
+use inflector::Inflector;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use serde_json::{Map, Value};
+
+#[derive(Debug, FromMeta)]
+pub struct RawAttrs {
+    /// The name of the generated trait.
+    /// Defaults to the contract's name, from the document's metadata,
+    /// in `PascalCase`.
+    /// eg. `trait = "RefFinance"`.
+    #[darling(default, rename = "trait")]
+    pub trait_name: Option<syn::Ident>,
+}
+
+/// The types from `near-sdk` that replace the definitions of the same
+/// name, as they have the same json representation.
+const NEAR_SDK_TYPES: &[(&str, &str)] = &[
+    ("AccountId", "AccountId"),
+    ("U128", "json_types::U128"),
+    ("U64", "json_types::U64"),
+    ("I128", "json_types::I128"),
+    ("I64", "json_types::I64"),
+    ("Base64VecU8", "json_types::Base64VecU8"),
+    ("PublicKey", "PublicKey"),
+];
+
+/// Reads the document, whose path is relative to the caller's
+/// `Cargo.toml`, and generates the items.
+///
+/// Methods that are private, or that have borsh arguments or returned
+/// values, are not included.
+pub fn import_abi(args: syn::AttributeArgs) -> error::Result<TokenStream2> {
+    let mut args = args.into_iter();
+    let path = match args.next() {
+        Some(syn::NestedMeta::Lit(syn::Lit::Str(path))) => path,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "Expected the path of the abi document, eg. `import_abi!(\"abi.json\")`",
+            )
+            .into())
+        }
+    };
+    let attrs = RawAttrs::from_list(&args.collect::<Vec<_>>())?;
+
+    let manifest_dir =
+        std::env::var("CARGO_MANIFEST_DIR").map_err(|e| syn::Error::new(path.span(), e))?;
+    let full_path = std::path::Path::new(&manifest_dir).join(path.value());
+    let full_path_str = full_path.display().to_string();
+    let abi: Value = std::fs::read_to_string(&full_path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        .map_err(|e| {
+            syn::Error::new(
+                path.span(),
+                format!("Failed to read the abi `{}`: {}", full_path_str, e),
+            )
+        })?;
+
+    let trait_name =
+        match (
+            attrs.trait_name,
+            abi.pointer("/metadata/name").and_then(Value::as_str),
+        ) {
+            (Some(trait_name), _) => trait_name,
+            (None, Some(name)) => type_ident(name),
+            (None, None) => return Err(syn::Error::new(
+                path.span(),
+                "The abi has no name, so the trait's name must be set, eg. `trait = \"Contract\"`",
+            )
+            .into()),
+        };
+
+    let empty = Map::new();
+    let definitions = abi
+        .pointer("/body/root_schema/definitions")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let interface = crate::crate_name("contract-interface")?;
+    let types = Types {
+        definitions,
+        near_sdk: crate::crate_name("near-sdk")?,
+        interface: interface.clone(),
+    };
+    let serde_crate_str = format!("{}::serde", crate::crate_name_str("near-sdk")?);
+
+    let definition_items = definitions
+        .iter()
+        .filter(|(name, _)| types.near_sdk_type(name).is_none())
+        .map(|(name, schema)| types.definition(name, schema, &serde_crate_str))
+        .collect::<Vec<_>>();
+
+    let functions = abi
+        .pointer("/body/functions")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let mut trait_methods = vec![];
+    for function in functions {
+        let name = match function.get("name").and_then(Value::as_str) {
+            Some(name) => name,
+            None => continue,
+        };
+        let modifiers = function
+            .get("modifiers")
+            .and_then(Value::as_array)
+            .map(|m| m.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let is_borsh =
+            |pointer: &str| function.pointer(pointer).and_then(Value::as_str) == Some("borsh");
+        if modifiers.contains(&"private")
+            || is_borsh("/params/serialization_type")
+            || is_borsh("/result/serialization_type")
+        {
+            continue;
+        }
+
+        let method_name = field_ident(name);
+        let (arg_names, arg_types): (Vec<_>, Vec<_>) = function
+            .pointer("/params/args")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|arg| {
+                let name = arg.get("name").and_then(Value::as_str)?;
+                let ty = types.schema_type(arg.get("type_schema").unwrap_or(&Value::Null));
+                Some((field_ident(name), ty))
+            })
+            .unzip();
+
+        let is_init = modifiers.contains(&"init");
+        let is_view = function.get("kind").and_then(Value::as_str) == Some("view");
+        // init methods don't return `Self`, so that no method mentions
+        // it and the builders don't need a `_State`
+        let (receiver, ret, kind_doc) = if is_init {
+            (quote!(), quote!(), " Initializes the contract.")
+        } else {
+            let receiver = if is_view {
+                quote! {&self,}
+            } else {
+                quote! {&mut self,}
+            };
+            let ret = match function.pointer("/result/type_schema") {
+                Some(schema) => {
+                    let ty = types.schema_type(schema);
+                    quote! {-> #ty}
+                }
+                None => quote!(),
+            };
+            let kind_doc = if is_view {
+                " A view method."
+            } else {
+                " A call method."
+            };
+            (receiver, ret, kind_doc)
+        };
+        let payable_doc = if modifiers.contains(&"payable") {
+            quote! {#[doc = " Accepts an attached deposit."]}
+        } else {
+            quote!()
+        };
+
+        trait_methods.push(quote! {
+            #[doc = #kind_doc]
+            #payable_doc
+            fn #method_name(#receiver #(#arg_names: #arg_types),*) #ret;
+        });
+    }
+
+    let trait_doc = format!(" Imported from the abi `{}`.", path.value());
+
+    Ok(quote! {
+        // makes the document a dependency, so that changes to it are seen
+        const _: &str = include_str!(#full_path_str);
+
+        #[doc = #trait_doc]
+        #[#interface::contract]
+        pub trait #trait_name {
+            #(#trait_methods)*
+        }
+
+        #(#definition_items)*
+    })
+}
+
+/// Converts the json schemas of the document into Rust types.
+struct Types<'a> {
+    definitions: &'a Map<String, Value>,
+    near_sdk: syn::Ident,
+    /// How `contract_interface` is named.
+    interface: syn::Ident,
+}
+
+impl<'a> Types<'a> {
+    /// The type from `near-sdk` that replaces a definition.
+    fn near_sdk_type(&self, definition: &str) -> Option<TokenStream2> {
+        let near_sdk = &self.near_sdk;
+        NEAR_SDK_TYPES
+            .iter()
+            .find(|(name, _)| *name == definition)
+            .map(|(_, path)| {
+                let path: syn::Path = syn::parse_str(path).unwrap();
+                quote! {#near_sdk::#path}
+            })
+    }
+
+    fn json_value(&self) -> TokenStream2 {
+        let near_sdk = &self.near_sdk;
+        quote! {#near_sdk::serde_json::Value}
+    }
+
+    /// The type described by a schema, where types that can't be
+    /// described are kept as json values.
+    fn schema_type(&self, schema: &Value) -> TokenStream2 {
+        let schema = match schema.as_object() {
+            Some(schema) => schema,
+            None => return self.json_value(),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.rsplit('/').next().unwrap_or(reference);
+            return match self.near_sdk_type(name) {
+                Some(ty) => ty,
+                None if self.definitions.contains_key(name) => {
+                    let ident = type_ident(name);
+                    quote! {#ident}
+                }
+                None => self.json_value(),
+            };
+        }
+
+        // `T | null`, from `anyOf` or from a list of types
+        for key in &["anyOf", "oneOf"] {
+            if let Some(variants) = schema.get(*key).and_then(Value::as_array) {
+                let (nulls, others): (Vec<_>, Vec<_>) = variants
+                    .iter()
+                    .partition(|v| v.get("type").and_then(Value::as_str) == Some("null"));
+                return match (nulls.len(), others.as_slice()) {
+                    (1, [other]) => {
+                        let ty = self.schema_type(other);
+                        quote! {Option<#ty>}
+                    }
+                    (0, [other]) => self.schema_type(other),
+                    _ => self.json_value(),
+                };
+            }
+        }
+        if let Some([single]) = schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+        {
+            return self.schema_type(single);
+        }
+        if let Some(instance_types) = schema.get("type").and_then(Value::as_array) {
+            let others = instance_types
+                .iter()
+                .filter(|t| t.as_str() != Some("null"))
+                .collect::<Vec<_>>();
+            let mut single = schema.clone();
+            return match others.as_slice() {
+                [other] if others.len() < instance_types.len() => {
+                    single.insert("type".to_string(), (*other).clone());
+                    let ty = self.schema_type(&Value::Object(single));
+                    quote! {Option<#ty>}
+                }
+                [other] => {
+                    single.insert("type".to_string(), (*other).clone());
+                    self.schema_type(&Value::Object(single))
+                }
+                _ => self.json_value(),
+            };
+        }
+
+        match schema.get("type").and_then(Value::as_str) {
+            Some("boolean") => quote! {bool},
+            Some("string") => quote! {String},
+            Some("number") => quote! {f64},
+            Some("null") => quote! {()},
+            Some("integer") => {
+                let format = schema.get("format").and_then(Value::as_str);
+                let unsigned = matches!(
+                    schema.get("minimum").and_then(Value::as_f64),
+                    Some(min) if min >= 0.0
+                );
+                match format {
+                    Some("uint8") => quote! {u8},
+                    Some("uint16") => quote! {u16},
+                    Some("uint32") => quote! {u32},
+                    Some("uint64") => quote! {u64},
+                    Some("uint128") => quote! {u128},
+                    Some("int8") => quote! {i8},
+                    Some("int16") => quote! {i16},
+                    Some("int32") => quote! {i32},
+                    Some("int128") => quote! {i128},
+                    Some("int64") => quote! {i64},
+                    Some(_) | None if unsigned => quote! {u64},
+                    _ => quote! {i64},
+                }
+            }
+            Some("array") => match schema.get("items") {
+                Some(Value::Array(items)) => {
+                    let items = items.iter().map(|item| self.schema_type(item));
+                    quote! {(#(#items,)*)}
+                }
+                Some(item) => {
+                    let item = self.schema_type(item);
+                    quote! {Vec<#item>}
+                }
+                None => {
+                    let value = self.json_value();
+                    quote! {Vec<#value>}
+                }
+            },
+            Some("object") if !schema.contains_key("properties") => {
+                let value = match schema.get("additionalProperties") {
+                    Some(value @ Value::Object(_)) => self.schema_type(value),
+                    _ => self.json_value(),
+                };
+                quote! {std::collections::HashMap<String, #value>}
+            }
+            _ => self.json_value(),
+        }
+    }
+
+    /// The derives of the structs and enums, which also derive
+    /// `JsonSchema` with the `schemars` feature.
+    fn derives(&self, serde_crate_str: &str) -> TokenStream2 {
+        let near_sdk = &self.near_sdk;
+        let schema_derive = if cfg!(feature = "schemars") {
+            let interface = &self.interface;
+            let schemars_crate = format!("{}::schemars", interface);
+            quote! {
+                #[derive(#interface::schemars::JsonSchema)]
+                #[schemars(crate = #schemars_crate)]
+            }
+        } else {
+            quote!()
+        };
+        quote! {
+            #[derive(#near_sdk::serde::Serialize, #near_sdk::serde::Deserialize, Debug, Clone)]
+            #[serde(crate = #serde_crate_str)]
+            #schema_derive
+        }
+    }
+
+    /// With the `schemars` feature, describes the fields whose type is
+    /// from `near-sdk` by the ones from `contract_interface::schema`.
+    fn field_schema_attr(&self, ty: &TokenStream2) -> TokenStream2 {
+        if !cfg!(feature = "schemars") {
+            return quote!();
+        }
+        syn::parse2::<syn::Type>(ty.clone())
+            .ok()
+            .and_then(|ty| {
+                crate::core_impl::code_generator::schema_type(&ty, &self.interface, &self.near_sdk)
+            })
+            .map(|schema_ty| {
+                let schema_ty_str = quote!(#schema_ty).to_string();
+                quote! {#[schemars(with = #schema_ty_str)]}
+            })
+            .unwrap_or_default()
+    }
+
+    /// A struct for objects, an enum for strings that have a fixed set of
+    /// values, or otherwise a type alias.
+    fn definition(&self, name: &str, schema: &Value, serde_crate_str: &str) -> TokenStream2 {
+        let ident = type_ident(name);
+        let doc = schema
+            .get("description")
+            .and_then(Value::as_str)
+            .map(|d| format!(" {}", d))
+            .unwrap_or_else(|| format!(" Imported `{}` type.", name));
+        let derives = self.derives(serde_crate_str);
+
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            let required = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|r| r.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+                .unwrap_or_default();
+            let fields = properties.iter().map(|(field, field_schema)| {
+                let field_ident = field_ident(field);
+                let rename = if field_ident.to_string().trim_start_matches("r#") != field {
+                    quote! {#[serde(rename = #field)]}
+                } else {
+                    quote!()
+                };
+                let ty = self.schema_type(field_schema);
+                let field_doc = field_schema
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(|d| {
+                        let d = format!(" {}", d);
+                        quote! {#[doc = #d]}
+                    });
+                if required.contains(&field.as_str()) {
+                    let schema_attr = self.field_schema_attr(&ty);
+                    quote! {
+                        #field_doc
+                        #rename
+                        #schema_attr
+                        pub #field_ident: #ty,
+                    }
+                } else {
+                    let ty = if ty.to_string().starts_with("Option <") {
+                        ty
+                    } else {
+                        quote! {Option<#ty>}
+                    };
+                    let schema_attr = self.field_schema_attr(&ty);
+                    quote! {
+                        #field_doc
+                        #rename
+                        #[serde(default, skip_serializing_if = "Option::is_none")]
+                        #schema_attr
+                        pub #field_ident: #ty,
+                    }
+                }
+            });
+            return quote! {
+                #[doc = #doc]
+                #derives
+                pub struct #ident {
+                    #(#fields)*
+                }
+            };
+        }
+
+        let values = schema
+            .get("enum")
+            .and_then(Value::as_array)
+            .map(|values| values.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if !values.is_empty() {
+            let variants = values.iter().map(|value| {
+                let variant = type_ident(value);
+                quote! {
+                    #[serde(rename = #value)]
+                    #variant,
+                }
+            });
+            return quote! {
+                #[doc = #doc]
+                #derives
+                pub enum #ident {
+                    #(#variants)*
+                }
+            };
+        }
+
+        let ty = self.schema_type(schema);
+        quote! {
+            #[doc = #doc]
+            pub type #ident = #ty;
+        }
+    }
+}
+
+/// A valid type name, eg. `ref-finance` -> `RefFinance`.
+fn type_ident(name: &str) -> syn::Ident {
+    let name = sanitize(name).to_pascal_case();
+    let name = if name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+        format!("_{}", name)
+    } else {
+        name
+    };
+    syn::Ident::new(&name, Span::call_site())
+}
+
+/// A valid field or method name, which may be a raw identifier,
+/// eg. `type` -> `r#type`.
+fn field_ident(name: &str) -> syn::Ident {
+    let name = sanitize(name);
+    let name = if name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+        format!("_{}", name)
+    } else {
+        name
+    };
+    match syn::parse_str::<syn::Ident>(&name) {
+        Ok(ident) => ident,
+        // keywords, such as `type`, as raw identifiers
+        Err(_) => syn::parse_str::<syn::Ident>(&format!("r#{}", name))
+            .unwrap_or_else(|_| format_ident!("{}_", name)),
+    }
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema_type(schema: Value) -> String {
+        let definitions = json!({"Pool": {"type": "object", "properties": {}}});
+        let types = Types {
+            definitions: definitions.as_object().unwrap(),
+            near_sdk: format_ident!("near_sdk"),
+            interface: format_ident!("contract_interface"),
+        };
+        types.schema_type(&schema).to_string()
+    }
+
+    #[test]
+    fn test_nullable() {
+        let u128 = quote! {Option<near_sdk::json_types::U128>}.to_string();
+        let any_of = json!({"anyOf": [{"$ref": "#/definitions/U128"}, {"type": "null"}]});
+        assert_eq!(schema_type(any_of), u128);
+        let one_of = json!({"oneOf": [{"type": "null"}, {"$ref": "#/definitions/U128"}]});
+        assert_eq!(schema_type(one_of), u128);
+
+        let list = json!({"type": ["string", "null"]});
+        assert_eq!(schema_type(list), quote! {Option<String>}.to_string());
+        let pool = json!({"anyOf": [{"$ref": "#/definitions/Pool"}, {"type": "null"}]});
+        assert_eq!(schema_type(pool), quote! {Option<Pool>}.to_string());
+
+        // more than one non-null type can't be described
+        let value = quote! {near_sdk::serde_json::Value}.to_string();
+        let many = json!({"anyOf": [{"type": "string"}, {"type": "boolean"}]});
+        assert_eq!(schema_type(many), value);
+        let unknown = json!({"$ref": "#/definitions/Unknown"});
+        assert_eq!(schema_type(unknown), value);
+    }
+
+    #[test]
+    fn test_integer_formats() {
+        let integer = |format: Value, minimum: Value| {
+            let mut schema = json!({"type": "integer"});
+            if !format.is_null() {
+                schema["format"] = format;
+            }
+            if !minimum.is_null() {
+                schema["minimum"] = minimum;
+            }
+            schema_type(schema)
+        };
+        assert_eq!(integer(json!("uint8"), json!(0.0)), "u8");
+        assert_eq!(integer(json!("uint32"), json!(0.0)), "u32");
+        assert_eq!(integer(json!("uint128"), json!(0.0)), "u128");
+        assert_eq!(integer(json!("int16"), Value::Null), "i16");
+        assert_eq!(integer(json!("int64"), Value::Null), "i64");
+        // without a known format, the sign is given by the minimum
+        assert_eq!(integer(Value::Null, json!(0)), "u64");
+        assert_eq!(integer(json!("uint"), json!(0.0)), "u64");
+        assert_eq!(integer(Value::Null, json!(-1)), "i64");
+        assert_eq!(integer(Value::Null, Value::Null), "i64");
+    }
+
+    #[test]
+    fn test_enum() {
+        let types = Types {
+            definitions: &Map::new(),
+            near_sdk: format_ident!("near_sdk"),
+            interface: format_ident!("contract_interface"),
+        };
+        let schema = json!({"type": "string", "enum": ["swap-all", "deposit"]});
+        let item = types.definition("pool-kind", &schema, "near_sdk::serde");
+        let derives = types.derives("near_sdk::serde");
+        let expected = quote! {
+            #[doc = " Imported `pool-kind` type."]
+            #derives
+            pub enum PoolKind {
+                #[serde(rename = "swap-all")]
+                SwapAll,
+                #[serde(rename = "deposit")]
+                Deposit,
+            }
+        };
+        assert_eq!(item.to_string(), expected.to_string());
+
+        // without values, a string is only an alias
+        let schema = json!({"type": "string"});
+        let item = types.definition("Name", &schema, "near_sdk::serde");
+        let expected = quote! {
+            #[doc = " Imported `Name` type."]
+            pub type Name = String;
+        };
+        assert_eq!(item.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_idents() {
+        assert_eq!(field_ident("type").to_string(), "r#type");
+        assert_eq!(field_ident("match").to_string(), "r#match");
+        // can't be raw identifiers
+        assert_eq!(field_ident("self").to_string(), "self_");
+        assert_eq!(field_ident("crate").to_string(), "crate_");
+
+        assert_eq!(field_ident("token-id").to_string(), "token_id");
+        assert_eq!(field_ident("1st").to_string(), "_1st");
+        assert_eq!(type_ident("ref-finance").to_string(), "RefFinance");
+        assert_eq!(type_ident("2x").to_string(), "_2X");
+    }
+}
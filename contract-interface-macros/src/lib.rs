@@ -5,6 +5,7 @@ mod core_impl;
 mod crate_name;
 mod error;
 mod get_ident;
+mod import_abi;
 mod replace_ident;
 mod replace_type_ident;
 
@@ -22,6 +23,17 @@ use quote::quote;
 /// When applied in a root item such as a trait or on an impl item,
 /// this macro generates a `mod` containing contract-related information.
 ///
+/// When applied on a struct with `state_versions(..)`, it generates
+/// the stored versions of that state. With `lazy_fields`, it instead
+/// generates a storage key for each of the struct's fields.
+/// With `json_schema`, the struct is instead a type used by methods,
+/// which derives `JsonSchema` when the `schemars` feature is enabled.
+///
+/// When applied on an enum with `event(standard = "..", version = "..")`,
+/// it generates the NEP-297 events for each of it's variants.
+/// Events are only declared on enums, as `#[contract]` on a trait
+/// already declares the methods of a contract.
+///
 /// It also searches for more `#[contract]` attributes on inner items,
 /// such as on methods or their arguments, which can configure the
 /// generated code.
@@ -48,7 +60,7 @@ fn contract_internal(
         Ok(item_trait_info.wrapped_module()?.into())
     }
     // attached on `impl Trait for Struct {}`
-    else if let Ok(mut item_impl) = syn::parse::<syn::ItemImpl>(item) {
+    else if let Ok(mut item_impl) = syn::parse::<syn::ItemImpl>(item.clone()) {
         let item_impl_info =
             info_extractor::item_impl_info::ItemImplInfo::new(&mut item_impl, attr_args)?;
         let generated_code = item_impl_info.wrapper_code()?;
@@ -60,12 +72,56 @@ fn contract_internal(
             // #marshalled_code
         }))
     }
+    // attached on `struct Struct {}`, for versioned or lazy states,
+    // or for types that have a json schema
+    else if let Ok(mut item_struct) = syn::parse::<syn::ItemStruct>(item.clone()) {
+        let item_struct_info =
+            info_extractor::item_struct_info::ItemStructInfo::new(&mut item_struct, attr_args)?;
+        Ok(item_struct_info.wrapped_module()?.into())
+    }
+    // attached on `enum Enum {}`, for events
+    else if let Ok(mut item_enum) = syn::parse::<syn::ItemEnum>(item) {
+        let item_enum_info =
+            info_extractor::item_enum_info::ItemEnumInfo::new(&mut item_enum, attr_args)?;
+        Ok(item_enum_info.wrapped_module()?.into())
+    }
     // invalid root #[contract] attribute attachment
     else {
         Err(syn::Error::new(
             Span::call_site(),
-            "`contract` can only be used on trait definitions or on implementations. Perhaps a `#[contract]` attribute is missing at the parent item?",
+            "`contract` can only be used on trait definitions, on implementations, on structs or on event enums. Perhaps a `#[contract]` attribute is missing at the parent item?",
         )
         .into())
     }
 }
+
+/// Generates a `#[contract]` trait from a [NEAR ABI](https://github.com/near/abi)
+/// document, so that contracts that were not written with this crate
+/// can be called with the trait's request builders.
+///
+/// The document's path is relative to the `Cargo.toml` of the crate
+/// that uses the macro, and the types that it defines are also
+/// generated, as serde structs, enums or aliases.
+/// No method mentions `Self`, as init methods return nothing, so the
+/// builders don't need a `_State`.
+///
+/// eg.
+/// ```ignore
+/// contract_interface::import_abi!("ref_finance_abi.json", trait = "RefFinance");
+///
+/// let _promise = ref_finance::get_pool::Request::contract(account_id)
+///     .args(0)
+///     .prepaid_gas(gas)
+///     .request();
+/// ```
+///
+/// Private methods, and methods that have borsh arguments or returned
+/// values, are not included.
+#[proc_macro]
+pub fn import_abi(input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(input as syn::AttributeArgs);
+    match import_abi::import_abi(args) {
+        Ok(ok) => ok.into(),
+        Err(e) => e.into_token_stream(),
+    }
+}
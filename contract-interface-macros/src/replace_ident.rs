@@ -18,6 +18,21 @@ pub fn replace_ident_from_self_to_ident<T: ReplaceIdent>(t: &mut T, ident: &syn:
     t.replace_ident(&_self, ident);
 }
 
+/// Whether the tokens mention `_State`, which replaces `Self`.
+pub fn mentions_state(tokens: &proc_macro2::TokenStream) -> bool {
+    mentions_ident(tokens, "_State")
+}
+
+/// Whether the tokens mention the identifier, such as a generic.
+pub fn mentions_ident(tokens: &proc_macro2::TokenStream, name: &str) -> bool {
+    use proc_macro2::TokenTree;
+    tokens.clone().into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions_ident(&group.stream(), name),
+        _ => false,
+    })
+}
+
 impl ReplaceIdent for syn::PatBox {
     fn replace_ident(&mut self, from: &syn::Ident, to: &syn::Ident) {
         self.pat.as_mut().replace_ident(from, to);
@@ -0,0 +1,441 @@
+//! A [NEAR ABI](https://github.com/near/abi) document describing the
+//! methods served by a contract, enabled by the `schemars` feature.
+//!
+//! The types of the json arguments and returns are described by the
+//! schemas of the `json_schema()` function of each trait, whose
+//! definitions are collected into the `root_schema` of the document.
+//!
+//! See [`extern_contract_abi!`](crate::extern_contract_abi).
+
+use crate::interface_info::MethodInterface;
+use crate::{Json, ToBytes};
+use near_sdk::{env, serde::Serialize, serde_json};
+use schemars::schema::{RootSchema, Schema, SchemaObject};
+
+/// The version of the NEAR ABI schema that is followed.
+pub const SCHEMA_VERSION: &str = "0.3.0";
+
+/// The root of the ABI document.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiRoot {
+    pub schema_version: String,
+    pub metadata: AbiMetadata,
+    pub body: AbiBody,
+}
+
+/// Information about the contract's package.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiBody {
+    pub functions: Vec<AbiFunction>,
+    /// Has the definitions referred by the schemas of the functions.
+    pub root_schema: RootSchema,
+}
+
+/// A served method.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiFunction {
+    /// The exported name of the method.
+    pub name: String,
+    pub kind: AbiFunctionKind,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub modifiers: Vec<AbiFunctionModifier>,
+    #[serde(skip_serializing_if = "AbiParameters::is_empty")]
+    pub params: AbiParameters,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<AbiType>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum AbiFunctionKind {
+    /// The method doesn't change the state.
+    View,
+    Call,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum AbiFunctionModifier {
+    Init,
+    Payable,
+    Private,
+}
+
+/// The arguments of a method, in the serialization format of the method,
+/// eg. `json` or `borsh`.
+///
+/// Only json arguments have schemas, and the others accept any value.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiParameters {
+    pub serialization_type: &'static str,
+    pub args: Vec<AbiParameter>,
+}
+
+impl AbiParameters {
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+}
+
+/// A single argument of a method.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiParameter {
+    pub name: String,
+    pub type_schema: Schema,
+}
+
+/// The value returned by a method, in it's serialization format.
+///
+/// As with the arguments, only json values have schemas.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AbiType {
+    pub serialization_type: &'static str,
+    pub type_schema: Schema,
+}
+
+/// The schemas of a method, as found in the schema of it's trait.
+struct MethodSchemas<'a> {
+    args: Option<&'a SchemaObject>,
+    ret: Option<&'a Schema>,
+}
+
+impl<'a> MethodSchemas<'a> {
+    /// Finds the method in the schemas of the traits, which are titled
+    /// by the trait names.
+    fn find(schemas: &'a [RootSchema], trait_name: &str, method_name: &str) -> Option<Self> {
+        let root = schemas.iter().find(|root| {
+            root.schema
+                .metadata
+                .as_ref()
+                .and_then(|m| m.title.as_deref())
+                == Some(trait_name)
+        })?;
+        let method = match root.schema.object.as_ref()?.properties.get(method_name)? {
+            Schema::Object(method) => method.object.as_ref()?,
+            Schema::Bool(_) => return None,
+        };
+        let args = method
+            .properties
+            .get("args")
+            .and_then(|args| Self::resolve(root, args));
+        let ret = method.properties.get("return");
+        Some(Self { args, ret })
+    }
+
+    /// Follows a reference into the definitions of the trait's schema.
+    fn resolve(root: &'a RootSchema, schema: &'a Schema) -> Option<&'a SchemaObject> {
+        let object = match schema {
+            Schema::Object(object) => object,
+            Schema::Bool(_) => return None,
+        };
+        match &object.reference {
+            Some(reference) => {
+                let name = reference.strip_prefix("#/definitions/")?;
+                match root.definitions.get(name)? {
+                    Schema::Object(object) => Some(object),
+                    Schema::Bool(_) => None,
+                }
+            }
+            None => Some(object),
+        }
+    }
+
+    /// The schema of an argument, which is any value if it's unknown.
+    fn arg(&self, name: &str) -> Schema {
+        self.args
+            .and_then(|args| args.object.as_ref())
+            .and_then(|args| args.properties.get(name))
+            .cloned()
+            .unwrap_or(Schema::Bool(true))
+    }
+}
+
+impl AbiFunction {
+    /// Describes a method with the schemas of the traits, where methods
+    /// that are missing from them (such as the ones with their own
+    /// generics, or that don't take json args) have arguments and
+    /// returns that accept any value.
+    pub fn new(method: &MethodInterface, schemas: &[RootSchema]) -> Self {
+        use crate::ReceiverKind;
+        let kind = if method.view {
+            AbiFunctionKind::View
+        } else {
+            AbiFunctionKind::Call
+        };
+        let mut modifiers = vec![];
+        if let ReceiverKind::StatelessInit = method.receiver {
+            modifiers.push(AbiFunctionModifier::Init);
+        }
+        if method.payable {
+            modifiers.push(AbiFunctionModifier::Payable);
+        }
+        if method.private {
+            modifiers.push(AbiFunctionModifier::Private);
+        }
+        let found = MethodSchemas::find(schemas, method.trait_name, &method.name);
+        // only json args and returns have schemas, and the others
+        // accept any value
+        let args = method
+            .args
+            .iter()
+            .map(|arg| AbiParameter {
+                name: arg.name.to_string(),
+                type_schema: found
+                    .as_ref()
+                    .map(|found| found.arg(arg.name))
+                    .unwrap_or(Schema::Bool(true)),
+            })
+            .collect();
+        let result = method.return_type.map(|_| AbiType {
+            serialization_type: method.return_format,
+            type_schema: found
+                .as_ref()
+                .and_then(|found| found.ret.cloned())
+                .unwrap_or(Schema::Bool(true)),
+        });
+        AbiFunction {
+            name: method.name.clone(),
+            kind,
+            modifiers,
+            params: AbiParameters {
+                serialization_type: method.args_format,
+                args,
+            },
+            result,
+        }
+    }
+}
+
+impl AbiRoot {
+    /// An empty document, which then gets the methods of each
+    /// implementation with [`AbiRoot::add_methods`].
+    pub fn new(name: &str, version: &str) -> Self {
+        use schemars::gen::SchemaSettings;
+        Self {
+            schema_version: SCHEMA_VERSION.to_string(),
+            metadata: AbiMetadata {
+                name: Some(name.to_string()),
+                version: Some(version.to_string()),
+            },
+            body: AbiBody {
+                functions: vec![],
+                root_schema: RootSchema {
+                    meta_schema: SchemaSettings::draft07().meta_schema,
+                    schema: SchemaObject::default(),
+                    definitions: Default::default(),
+                },
+            },
+        }
+    }
+
+    /// Adds the methods of an implementation, as given by the `methods()`
+    /// and `json_schemas()` functions of it's module, exported with a
+    /// `prefix`.
+    ///
+    /// The definitions of the schemas are added to the `root_schema`.
+    ///
+    /// Panics if a definition has the same name as a different one that
+    /// was already added, since their references couldn't be told apart.
+    pub fn add_methods(
+        &mut self,
+        prefix: &str,
+        methods: &[MethodInterface],
+        schemas: &[RootSchema],
+    ) {
+        for method in methods {
+            let mut function = AbiFunction::new(method, schemas);
+            function.name = format!("{}{}", prefix, method.name);
+            self.body.functions.push(function);
+        }
+        for schema in schemas {
+            for (name, definition) in &schema.definitions {
+                let definitions = &mut self.body.root_schema.definitions;
+                match definitions.get(name) {
+                    Some(existing) if existing != definition => panic!(
+                        "The definition `{}` has different schemas, from different types with the same name.",
+                        name
+                    ),
+                    Some(_) => {}
+                    None => {
+                        definitions.insert(name.clone(), definition.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// The document as pretty-printed json, such as for writing it
+    /// into a file from a test.
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize the abi.")
+    }
+
+    /// Returns the document, serialized as json, as the method's output.
+    pub fn serve(&self) {
+        env::setup_panic_hook();
+        let bytes =
+            <Self as ToBytes<Json>>::to_bytes(self).expect("Failed to serialize the return value.");
+        env::value_return(&bytes);
+    }
+}
+
+/// Generates the `__contract_abi` view method, which returns the
+/// [`AbiRoot`] document, and also a `contract_abi()` function so that
+/// the same document can be produced natively, such as from a test.
+///
+/// The package's name and version are taken from the crate that
+/// calls this macro.
+/// The `impl_mods` are the same as for
+/// [`extern_contract_interface!`](crate::extern_contract_interface),
+/// and their `json_schemas()` functions describe the arguments and
+/// returns.
+///
+/// eg.
+/// ```ignore
+/// contract_interface::extern_contract_abi!(
+///     impl_mods = [super::impl_fungible_token, super::impl_counter(prefix = "a_")]
+/// );
+/// ```
+#[macro_export]
+macro_rules! extern_contract_abi {
+    (
+        impl_mods = [$($($impl_mod:ident)::+ $((prefix = $prefix:literal))?),* $(,)?]
+    ) => {
+        /// The abi of the contract.
+        pub fn contract_abi() -> $crate::abi::AbiRoot {
+            #[allow(unused_mut)]
+            let mut abi =
+                $crate::abi::AbiRoot::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+            $(
+                abi.add_methods(
+                    concat!($($prefix)?),
+                    &$($impl_mod::)+methods(),
+                    &$($impl_mod::)+json_schemas(),
+                );
+            )*
+            abi
+        }
+
+        #[no_mangle]
+        pub extern "C" fn __contract_abi() {
+            contract_abi().serve();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface_info::{ArgInterface, MethodInterface};
+    use crate::schema::TraitSchema;
+    use crate::ReceiverKind;
+    use schemars::JsonSchema;
+
+    fn method(
+        name: &str,
+        args_format: &'static str,
+        return_format: &'static str,
+    ) -> MethodInterface {
+        MethodInterface {
+            name: name.to_string(),
+            trait_name: "Trait",
+            receiver: ReceiverKind::Ref,
+            view: true,
+            payable: false,
+            private: false,
+            args_format,
+            return_format,
+            args: ArgInterface::from_names_types(&["a"], &["u8"], &[false]),
+            return_type: Some("u8"),
+            default_gas: None,
+            default_deposit: None,
+        }
+    }
+
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars")]
+    #[allow(dead_code)]
+    struct Args {
+        a: u8,
+    }
+
+    #[test]
+    fn test_formats() {
+        let schemas = [TraitSchema::new("Trait")
+            .method_with_return::<Args, u8>("json")
+            .into_root_schema()];
+        let any = Schema::Bool(true);
+
+        let json = AbiFunction::new(&method("json", "json", "json"), &schemas);
+        assert_eq!(json.params.serialization_type, "json");
+        assert_ne!(json.params.args[0].type_schema, any);
+        let result = json.result.unwrap();
+        assert_eq!(result.serialization_type, "json");
+        assert_ne!(result.type_schema, any);
+
+        // other formats have no schemas, but are still described
+        let borsh = AbiFunction::new(&method("borsh", "borsh", "borsh"), &schemas);
+        assert_eq!(borsh.params.serialization_type, "borsh");
+        assert_eq!(borsh.params.args[0].name, "a");
+        assert_eq!(borsh.params.args[0].type_schema, any);
+        let result = borsh.result.unwrap();
+        assert_eq!(result.serialization_type, "borsh");
+        assert_eq!(result.type_schema, any);
+
+        let raw = AbiFunction::new(&method("raw", "raw", "raw"), &schemas);
+        assert_eq!(raw.params.serialization_type, "raw");
+        assert_eq!(raw.result.unwrap().serialization_type, "raw");
+
+        // while methods that return nothing have no result
+        let mut void = method("void", "json", "json");
+        void.return_type = None;
+        assert!(AbiFunction::new(&void, &schemas).result.is_none());
+    }
+
+    mod a {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        pub struct Metadata {
+            pub a: u8,
+        }
+    }
+
+    mod b {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        pub struct Metadata {
+            pub b: String,
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The definition `Metadata` has different schemas")]
+    fn test_definition_collision() {
+        let mut abi = AbiRoot::new("name", "0.0.0");
+        let a = [TraitSchema::new("A")
+            .method::<a::Metadata>("a")
+            .into_root_schema()];
+        let b = [TraitSchema::new("B")
+            .method::<b::Metadata>("b")
+            .into_root_schema()];
+        abi.add_methods("", &[], &a);
+        // the same definitions can be added again
+        abi.add_methods("", &[], &a);
+        abi.add_methods("", &[], &b);
+    }
+}
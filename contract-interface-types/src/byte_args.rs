@@ -1,8 +1,31 @@
 pub mod borsh;
 pub mod json;
+pub mod raw;
 
 pub use borsh::Borsh;
-pub use json::Json;
+pub use json::{Json, JsonPathError, JsonPositional};
+pub use raw::Raw;
+
+/// The name of a serialization format, eg. `"json"`.
+pub trait FormatName {
+    const NAME: &'static str;
+}
+
+impl FormatName for Json {
+    const NAME: &'static str = "json";
+}
+
+impl FormatName for JsonPositional {
+    const NAME: &'static str = "json_positional";
+}
+
+impl FormatName for Borsh {
+    const NAME: &'static str = "borsh";
+}
+
+impl FormatName for Raw {
+    const NAME: &'static str = "raw";
+}
 
 pub trait ToBytes<Type>: Sized {
     type Error: std::fmt::Debug;
@@ -13,3 +36,13 @@ pub trait FromBytes<Type>: Sized {
     type Error: std::fmt::Debug;
     fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error>;
 }
+
+/// Like [`FromBytes`], but the value may borrow from the bytes,
+/// such as `&str`, `&[u8]` and `Cow` arguments that are read from the
+/// input without being copied.
+///
+/// The error is also displayed, as it's shown to the caller.
+pub trait FromBorrowedBytes<'a, Type>: Sized {
+    type Error: std::fmt::Debug + std::fmt::Display;
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error>;
+}
@@ -1,4 +1,4 @@
-use crate::byte_args::{FromBytes, ToBytes};
+use crate::byte_args::{FromBorrowedBytes, FromBytes, ToBytes};
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 
 pub struct Borsh;
@@ -24,3 +24,14 @@ where
         BorshDeserialize::try_from_slice(bytes)
     }
 }
+
+impl<'a, T> FromBorrowedBytes<'a, Borsh> for T
+where
+    T: BorshDeserialize,
+{
+    type Error = std::io::Error;
+
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        BorshDeserialize::try_from_slice(bytes)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::byte_args::{FromBytes, ToBytes};
-use near_sdk::serde::{de::DeserializeOwned, Serialize};
+use crate::byte_args::{FromBorrowedBytes, FromBytes, ToBytes};
+use near_sdk::serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub struct Json;
 
@@ -24,56 +24,322 @@ where
     }
 }
 
-// based on https://github.com/serde-rs/serde/issues/766#issuecomment-280353386
-pub mod named {
-    use super::Serialize;
+impl<'a, T> FromBorrowedBytes<'a, Json> for T
+where
+    T: Deserialize<'a>,
+{
+    type Error = JsonPathError;
+
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        from_slice_with_path(bytes)
+    }
+}
 
-    pub struct NamedJson1<T0> {
-        name0: String,
-        value0: T0,
+/// A json error, together with the path of the value that failed,
+/// eg. `amount` or `accounts[1]`.
+#[derive(Debug)]
+pub struct JsonPathError {
+    /// Empty when the error is not inside of a value.
+    pub path: String,
+    pub error: near_sdk::serde_json::Error,
+}
+
+impl From<near_sdk::serde_json::Error> for JsonPathError {
+    fn from(error: near_sdk::serde_json::Error) -> Self {
+        Self {
+            path: String::new(),
+            error,
+        }
+    }
+}
+
+impl std::fmt::Display for JsonPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.error)
+        } else {
+            write!(f, "{}: {}", self.path, self.error)
+        }
     }
-    impl<T0> NamedJson1<T0> {
-        pub fn new(name0: String, value0: T0) -> Self {
-            Self { name0, value0 }
+}
+
+/// Deserializes like `serde_json::from_slice`, but also
+/// tracks the path of the value being deserialized.
+fn from_slice_with_path<'a, T>(bytes: &'a [u8]) -> Result<T, JsonPathError>
+where
+    T: Deserialize<'a>,
+{
+    let mut de = near_sdk::serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|e| {
+        let path = e.path().to_string();
+        JsonPathError {
+            // the root value has no path
+            path: if path == "." { String::new() } else { path },
+            error: e.into_inner(),
         }
+    })?;
+    // only whitespace may follow the value
+    de.end()?;
+    Ok(value)
+}
+
+/// Json where the arguments are written as an array, in order,
+/// for contracts that expect positional arguments.
+///
+/// Implemented for tuples, eg. `(a, b)` is written as `[a, b]`
+/// and `()` as `[]`.
+pub struct JsonPositional;
+
+impl ToBytes<JsonPositional> for () {
+    type Error = near_sdk::serde_json::Error;
+    fn to_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+        Ok(b"[]".to_vec())
     }
+}
+
+impl FromBytes<JsonPositional> for () {
+    type Error = near_sdk::serde_json::Error;
 
-    impl<T0> Serialize for NamedJson1<T0>
-    where
-        T0: Serialize,
-    {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: near_sdk::serde::Serializer,
-        {
-            use near_sdk::serde::ser::SerializeMap;
-            let mut map = serializer.serialize_map(Some(1))?;
-            map.serialize_entry(&self.name0, &self.value0)?;
-            map.end()
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+        // otherwise serde would expect `null`
+        let values: Vec<near_sdk::serde_json::Value> = near_sdk::serde_json::from_slice(bytes)?;
+        if values.is_empty() {
+            Ok(())
+        } else {
+            use near_sdk::serde::de::Error;
+            Err(Self::Error::invalid_length(values.len(), &"an empty array"))
         }
     }
+}
+
+impl<'a> FromBorrowedBytes<'a, JsonPositional> for () {
+    type Error = JsonPathError;
 
-    pub struct NamedJson2<T0, T1> {
-        name0: String,
-        value0: T0,
-        name1: String,
-        value1: T1,
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(FromBytes::<JsonPositional>::from_bytes(bytes)?)
     }
+}
+
+macro_rules! json_positional {
+    ($(($($t:ident),+))+) => {
+        $(
+            impl<$($t),+> ToBytes<JsonPositional> for ($($t,)+)
+            where
+                $($t: Serialize,)+
+            {
+                type Error = near_sdk::serde_json::Error;
+                fn to_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+                    near_sdk::serde_json::to_vec(self)
+                }
+            }
 
-    impl<T0, T1> Serialize for NamedJson2<T0, T1>
-    where
-        T0: Serialize,
-        T1: Serialize,
-    {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: near_sdk::serde::Serializer,
-        {
-            use near_sdk::serde::ser::SerializeMap;
-            let mut map = serializer.serialize_map(Some(2))?;
-            map.serialize_entry(&self.name0, &self.value0)?;
-            map.serialize_entry(&self.name1, &self.value1)?;
-            map.end()
+            impl<$($t),+> FromBytes<JsonPositional> for ($($t,)+)
+            where
+                $($t: DeserializeOwned,)+
+            {
+                type Error = near_sdk::serde_json::Error;
+
+                fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+                    near_sdk::serde_json::from_slice(bytes)
+                }
+            }
+
+            impl<'a, $($t),+> FromBorrowedBytes<'a, JsonPositional> for ($($t,)+)
+            where
+                $($t: Deserialize<'a>,)+
+            {
+                type Error = JsonPathError;
+
+                fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    from_slice_with_path(bytes)
+                }
+            }
+        )+
+    };
+}
+
+json_positional! {
+    (T0)
+    (T0, T1)
+    (T0, T1, T2)
+    (T0, T1, T2, T3)
+    (T0, T1, T2, T3, T4)
+    (T0, T1, T2, T3, T4, T5)
+    (T0, T1, T2, T3, T4, T5, T6)
+    (T0, T1, T2, T3, T4, T5, T6, T7)
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8)
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)
+}
+
+// based on https://github.com/serde-rs/serde/issues/766#issuecomment-280353386
+/// Named arguments for requests that are not made with the generated
+/// builders, such as with [`ArgsRequest`](crate::request::ArgsRequest)
+/// when the method's name is only known at runtime.
+///
+/// eg. `NamedJson2::new("a".into(), 1, "b".into(), true)` is
+/// serialized as `{"a":1,"b":true}`.
+pub mod named {
+    use super::Serialize;
+    use near_sdk::serde_json::{self, Map, Value};
+
+    /// Named arguments that are set one by one at runtime, such as when
+    /// there are more of them than the fixed `NamedJson*` structures
+    /// have.
+    ///
+    /// eg. `NamedJson::new().arg("a", 1).arg("b", true)` is serialized
+    /// as `{"a":1,"b":true}`.
+    #[derive(Serialize, Debug, Clone, Default, PartialEq)]
+    #[serde(crate = "near_sdk::serde", transparent)]
+    pub struct NamedJson(Map<String, Value>);
+
+    impl NamedJson {
+        pub fn new() -> Self {
+            Self::default()
         }
+
+        /// Sets the argument, replacing the previous value of the same
+        /// name.
+        ///
+        /// Panics if the value can't be written as json, such as maps
+        /// whose keys are not strings.
+        pub fn arg<T: Serialize>(mut self, name: impl Into<String>, value: T) -> Self {
+            let name = name.into();
+            let value = serde_json::to_value(value)
+                .unwrap_or_else(|e| panic!("Failed to serialize the argument `{}`: {}", name, e));
+            self.0.insert(name, value);
+            self
+        }
+    }
+
+    macro_rules! named_json {
+        ($($named:ident { $($name:ident: $value:ident: $t:ident),+ })+) => {
+            $(
+                /// Serializes the values as a json object, each under it's name.
+                pub struct $named<$($t),+> {
+                    $(
+                        $name: String,
+                        $value: $t,
+                    )+
+                }
+
+                impl<$($t),+> $named<$($t),+> {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new($($name: String, $value: $t),+) -> Self {
+                        Self { $($name, $value),+ }
+                    }
+                }
+
+                impl<$($t),+> Serialize for $named<$($t),+>
+                where
+                    $($t: Serialize,)+
+                {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: near_sdk::serde::Serializer,
+                    {
+                        use near_sdk::serde::ser::SerializeMap;
+                        let len = [$(stringify!($t)),+].len();
+                        let mut map = serializer.serialize_map(Some(len))?;
+                        $(map.serialize_entry(&self.$name, &self.$value)?;)+
+                        map.end()
+                    }
+                }
+            )+
+        };
+    }
+
+    named_json! {
+        NamedJson1 { name0: value0: T0 }
+        NamedJson2 { name0: value0: T0, name1: value1: T1 }
+        NamedJson3 { name0: value0: T0, name1: value1: T1, name2: value2: T2 }
+        NamedJson4 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3 }
+        NamedJson5 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4 }
+        NamedJson6 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5 }
+        NamedJson7 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5, name6: value6: T6 }
+        NamedJson8 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5, name6: value6: T6, name7: value7: T7 }
+        NamedJson9 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5, name6: value6: T6, name7: value7: T7, name8: value8: T8 }
+        NamedJson10 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5, name6: value6: T6, name7: value7: T7, name8: value8: T8, name9: value9: T9 }
+        NamedJson11 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5, name6: value6: T6, name7: value7: T7, name8: value8: T8, name9: value9: T9, name10: value10: T10 }
+        NamedJson12 { name0: value0: T0, name1: value1: T1, name2: value2: T2, name3: value3: T3, name4: value4: T4, name5: value5: T5, name6: value6: T6, name7: value7: T7, name8: value8: T8, name9: value9: T9, name10: value10: T10, name11: value11: T11 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::named::{NamedJson, NamedJson3};
+    use super::{Json, JsonPositional};
+    use crate::{FromBytes, ToBytes};
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, AccountId};
+
+    #[test]
+    fn test_named_json() {
+        testing_env!(VMContextBuilder::new().build());
+
+        let args = NamedJson3::new(
+            "receiver_id".into(),
+            accounts(2),
+            "amount".into(),
+            U128(10),
+            "memo".into(),
+            None::<String>,
+        );
+        let bytes = ToBytes::<Json>::to_bytes(&args).unwrap();
+        assert_eq!(
+            bytes,
+            br#"{"receiver_id":"charlie","amount":"10","memo":null}"#.to_vec()
+        );
+        // usable with requests whose method name is only known at runtime
+        let _promise = crate::request::Request::contract(accounts(2))
+            .method("ft_transfer".to_string())
+            .args::<_, Json>(args)
+            .send_amount(1)
+            .prepaid_gas(near_sdk::Gas::from(5_000_000_000_000))
+            .request();
+
+        // any number of args, where a repeated name replaces the value
+        let args = (0..13).fold(NamedJson::new(), |args, i| args.arg(format!("a{}", i), i));
+        let args = args.arg("a0", "first");
+        let value: near_sdk::serde_json::Value =
+            FromBytes::<Json>::from_bytes(&ToBytes::<Json>::to_bytes(&args).unwrap()).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 13);
+        assert_eq!(value["a0"], "first");
+        assert_eq!(value["a12"], 12);
+    }
+
+    #[test]
+    fn test_borrowed_str() {
+        use crate::FromBorrowedBytes;
+        use std::borrow::Cow;
+        let input = br#""no escapes""#.to_vec();
+        let s: &str = FromBorrowedBytes::<Json>::from_borrowed_bytes(&input).unwrap();
+        assert_eq!(s, "no escapes");
+        assert_eq!(s.as_ptr(), input[1..].as_ptr());
+
+        // a `&str` can't hold the unescaped string, but a `Cow` can
+        let escaped = br#""with \"escapes\"""#.to_vec();
+        assert!(<&str as FromBorrowedBytes<Json>>::from_borrowed_bytes(&escaped).is_err());
+        let cow: Cow<str> = FromBorrowedBytes::<Json>::from_borrowed_bytes(&escaped).unwrap();
+        assert_eq!(cow, r#"with "escapes""#);
+    }
+
+    #[test]
+    fn test_json_positional() {
+        let args = (accounts(2), U128(10));
+        let bytes = ToBytes::<JsonPositional>::to_bytes(&args).unwrap();
+        assert_eq!(bytes, br#"["charlie","10"]"#.to_vec());
+        let (account_id, amount): (AccountId, U128) =
+            FromBytes::<JsonPositional>::from_bytes(&bytes).unwrap();
+        assert_eq!((account_id, amount), (accounts(2), U128(10)));
+
+        assert_eq!(
+            ToBytes::<JsonPositional>::to_bytes(&()).unwrap(),
+            b"[]".to_vec()
+        );
+        assert!(<() as FromBytes<JsonPositional>>::from_bytes(b"[]").is_ok());
+        assert!(<() as FromBytes<JsonPositional>>::from_bytes(b"[1]").is_err());
     }
 }
@@ -0,0 +1,71 @@
+use crate::byte_args::{FromBorrowedBytes, FromBytes, ToBytes};
+
+/// The bytes themselves, without any serialization, for methods that
+/// want the unparsed input or that return bytes as they are.
+///
+/// Only implemented for `Vec<u8>`, and for `&[u8]` and `Cow<[u8]>` when
+/// borrowing.
+pub struct Raw;
+
+impl ToBytes<Raw> for Vec<u8> {
+    type Error = std::convert::Infallible;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+        Ok(self.clone())
+    }
+}
+
+impl FromBytes<Raw> for Vec<u8> {
+    type Error = std::convert::Infallible;
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(bytes.to_vec())
+    }
+}
+
+impl<'a> FromBorrowedBytes<'a, Raw> for Vec<u8> {
+    type Error = std::convert::Infallible;
+
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(bytes.to_vec())
+    }
+}
+
+impl<'a> FromBorrowedBytes<'a, Raw> for &'a [u8] {
+    type Error = std::convert::Infallible;
+
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(bytes)
+    }
+}
+
+impl<'a> FromBorrowedBytes<'a, Raw> for std::borrow::Cow<'a, [u8]> {
+    type Error = std::convert::Infallible;
+
+    fn from_borrowed_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(std::borrow::Cow::Borrowed(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Raw;
+    use crate::{FormatName, FromBytes, ToBytes};
+
+    #[test]
+    fn test_raw_format() {
+        let bytes = b"{not json".to_vec();
+        assert_eq!(ToBytes::<Raw>::to_bytes(&bytes).unwrap(), bytes);
+        let read: Vec<u8> = FromBytes::<Raw>::from_bytes(&bytes).unwrap();
+        assert_eq!(read, bytes);
+        assert_eq!(<Raw as FormatName>::NAME, "raw");
+    }
+
+    #[test]
+    fn test_borrowed_bytes() {
+        use crate::FromBorrowedBytes;
+        let input = b"{not json".to_vec();
+        let bytes: &[u8] = FromBorrowedBytes::<Raw>::from_borrowed_bytes(&input).unwrap();
+        assert_eq!(bytes.as_ptr(), input.as_ptr());
+    }
+}
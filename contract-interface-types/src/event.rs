@@ -0,0 +1,68 @@
+//! Structured events, logged in the [NEP-297] format.
+//!
+//! Each event is logged as `EVENT_JSON:` followed by the json
+//! serialization of an [`EventLog`].
+//!
+//! [NEP-297]: https://nomicon.io/Standards/EventsFormat
+
+use crate::{Json, ToBytes};
+use near_sdk::{env, serde::Serialize};
+
+/// Identifies a group of events and their names, so off-chain tools
+/// can know which events a method may emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventMetadata {
+    /// The name of the standard, eg. `"nep141"`.
+    pub standard: &'static str,
+    /// The version of the standard, eg. `"1.0.0"`.
+    pub version: &'static str,
+    /// The names of all the events, eg. `["ft_mint", "ft_burn"]`.
+    pub events: &'static [&'static str],
+}
+
+/// A group of events that can be emitted.
+///
+/// Can be implemented on enums with
+/// `#[contract(event(standard = "nep141", version = "1.0.0"))]`,
+/// where each variant is an event.
+///
+/// There is no such attribute for traits: an event is a value that is
+/// built and then emitted, and methods refer to the type of their events
+/// with `emits(..)`. A trait, on the other hand, has no such value, and
+/// `#[contract]` on a trait already declares the methods of a contract.
+pub trait Event {
+    const METADATA: EventMetadata;
+
+    /// Logs the event.
+    fn emit(&self);
+}
+
+/// A single event, with it's standard, version, name and data.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EventLog<'a, Data> {
+    pub standard: &'static str,
+    pub version: &'static str,
+    pub event: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<&'a Data>,
+}
+
+impl<'a, Data> EventLog<'a, Data>
+where
+    Data: Serialize,
+{
+    /// The message that is logged for this event.
+    pub fn to_log(&self) -> String {
+        let bytes =
+            <Self as ToBytes<Json>>::to_bytes(self).expect("Failed to serialize the event.");
+        // json is always valid utf-8
+        let json = String::from_utf8(bytes).unwrap();
+        format!("EVENT_JSON:{}", json)
+    }
+
+    /// Logs the event.
+    pub fn emit(&self) {
+        env::log_str(&self.to_log());
+    }
+}
@@ -0,0 +1,113 @@
+//! Error encodings for methods that have `#[contract(handle_result)]`.
+//!
+//! Such methods return a `Result<T, E>`, where `T` is returned to the
+//! caller and `E` makes the method fail, aborting it through
+//! [`env::panic_str`].
+
+use near_sdk::env;
+
+/// Encodes an error into the message that the method will abort with.
+pub trait ErrorEncoding<E> {
+    fn encode(error: E) -> String;
+}
+
+/// Encodes the error with it's `Display` implementation.
+pub struct DisplayError;
+
+/// Encodes the error as json.
+pub struct JsonError;
+
+/// Encodes the error as a numeric code, from it's [`ErrorCode`]
+/// implementation.
+pub struct CodeError;
+
+/// Errors that can be represented as a numeric code.
+pub trait ErrorCode {
+    fn error_code(&self) -> u32;
+}
+
+impl<E> ErrorEncoding<E> for DisplayError
+where
+    E: std::fmt::Display,
+{
+    fn encode(error: E) -> String {
+        error.to_string()
+    }
+}
+
+impl<E> ErrorEncoding<E> for JsonError
+where
+    E: near_sdk::serde::Serialize,
+{
+    fn encode(error: E) -> String {
+        near_sdk::serde_json::to_string(&error).expect("Failed to serialize the error.")
+    }
+}
+
+impl<E> ErrorEncoding<E> for CodeError
+where
+    E: ErrorCode,
+{
+    fn encode(error: E) -> String {
+        error.error_code().to_string()
+    }
+}
+
+/// Gets the `Ok` value, or aborts the method with the encoded `Err` value.
+pub fn unwrap_or_panic<T, E, Encoding>(result: Result<T, E>) -> T
+where
+    Encoding: ErrorEncoding<E>,
+{
+    match result {
+        Ok(value) => value,
+        Err(error) => env::panic_str(&Encoding::encode(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unwrap_or_panic, CodeError, DisplayError, ErrorCode, ErrorEncoding, JsonError};
+    use near_sdk::serde::Serialize;
+
+    #[derive(Serialize)]
+    #[serde(crate = "near_sdk::serde")]
+    enum Error {
+        NotEnoughBalance { missing: u8 },
+    }
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Error::NotEnoughBalance { missing } => write!(f, "missing {} of balance", missing),
+            }
+        }
+    }
+
+    impl ErrorCode for Error {
+        fn error_code(&self) -> u32 {
+            match self {
+                Error::NotEnoughBalance { .. } => 1,
+            }
+        }
+    }
+
+    fn error() -> Error {
+        Error::NotEnoughBalance { missing: 2 }
+    }
+
+    #[test]
+    fn test_encodings() {
+        assert_eq!(DisplayError::encode(error()), "missing 2 of balance");
+        assert_eq!(
+            JsonError::encode(error()),
+            r#"{"NotEnoughBalance":{"missing":2}}"#
+        );
+        assert_eq!(CodeError::encode(error()), "1");
+    }
+
+    #[test]
+    fn test_unwrap_ok() {
+        let value = unwrap_or_panic::<_, _, DisplayError>(Ok::<u8, Error>(3));
+        assert_eq!(value, 3);
+    }
+}
@@ -1,16 +1,31 @@
+use crate::state::{StateRead, StateWrite};
 use near_sdk::{
     borsh::{BorshDeserialize, BorshSerialize},
     env,
 };
 pub use request::Request;
 
+/// The name that a method is exported as.
+///
+/// Each method has it's own `serve::Name`, while the `extern_*!` macros
+/// use a local type for it when they export the method with a prefix.
+pub trait ExportedName {
+    const NAME: &'static str;
+}
+
 ///
 /// `Diverged` is used to allow third-party specialization of this trait for arbitrary types.
 /// See [RFC 1023](https://github.com/rust-lang/rfcs/blob/master/text/1023-rebalancing-coherence.md)
 /// for more information.
 pub trait Serve<ArgsDeserialization, ReturnSerialization, Diverged = ()> {
-    type State: BorshDeserialize + BorshSerialize + Default;
-    type Args: crate::FromBytes<ArgsDeserialization>;
+    /// The name that the method is exported as, including any prefix,
+    /// shown in the errors of it's arguments.
+    const METHOD_NAME: &'static str;
+    /// The state doesn't need to be `Default`.
+    /// Only methods that allow a temporary state require it.
+    type State: BorshDeserialize + BorshSerialize;
+    /// The arguments may borrow from the input for `'args`.
+    type Args<'args>: crate::FromBorrowedBytes<'args, ArgsDeserialization>;
     type Return: crate::ToBytes<ReturnSerialization>;
 
     fn setup_panic_hook() {
@@ -35,25 +50,55 @@ pub trait Serve<ArgsDeserialization, ReturnSerialization, Diverged = ()> {
         }
     }
 
-    fn deserialize_args_from_input() -> Self::Args {
-        use crate::FromBytes;
-        // TODO: test with method without arguments
-        let bytes = env::input().expect("Expected input since method has arguments.");
-        Self::Args::from_bytes(bytes.as_ref()).expect("Failed to deserialize the argument values")
+    /// The arguments of a method that has none of them.
+    fn empty_args<'args>() -> Option<Self::Args<'args>> {
+        None
+    }
+
+    /// The input bytes, which the arguments borrow from.
+    fn input() -> Vec<u8> {
+        env::input().unwrap_or_default()
+    }
+
+    /// Methods without arguments accept an empty input, `{}` or `null`,
+    /// while any other input is read as their (empty) arguments, so that
+    /// eg. a number is an error.
+    ///
+    /// Otherwise panics with the method name and, for json, the path
+    /// of the failing argument, eg. `ft_transfer: amount: invalid type ..`.
+    fn deserialize_args_from_input(input: &[u8]) -> Self::Args<'_> {
+        use crate::FromBorrowedBytes;
+        if let Some(args) = Self::empty_args() {
+            let trimmed = String::from_utf8_lossy(input);
+            if matches!(trimmed.trim(), "" | "{}" | "null") {
+                return args;
+            }
+        }
+        match Self::Args::from_borrowed_bytes(input) {
+            Ok(args) => args,
+            Err(e) => env::panic_str(&format!("{}: {}", Self::METHOD_NAME, e)),
+        }
+    }
+
+    fn state_read<OuterType>() -> Option<OuterType>
+    where
+        OuterType: StateRead,
+    {
+        OuterType::state_read()
     }
 
     fn state_read_or_default<OuterType>() -> OuterType
     where
-        OuterType: Default + BorshDeserialize,
+        OuterType: Default + StateRead,
     {
-        env::state_read().unwrap_or_default()
+        OuterType::state_read().unwrap_or_default()
     }
 
     fn state_read_or_panic<OuterType>() -> OuterType
     where
-        OuterType: BorshDeserialize,
+        OuterType: StateRead,
     {
-        match env::state_read() {
+        match OuterType::state_read() {
             Some(state) => state,
             None => env::panic_str("State must be first initialized"),
         }
@@ -72,28 +117,33 @@ pub trait Serve<ArgsDeserialization, ReturnSerialization, Diverged = ()> {
         env::value_return(&result);
     }
 
+    /// The serialized state, used to check whether a method changed it.
+    fn state_bytes(state: &Self::State) -> Vec<u8> {
+        state.try_to_vec().expect("Failed to serialize the state.")
+    }
+
     fn state_write<OuterType>(contract: &OuterType)
     where
-        OuterType: BorshSerialize,
+        OuterType: StateWrite,
     {
-        env::state_write(contract);
+        contract.state_write();
     }
 }
 
 pub trait ServeRefMut<ArgsDeserialization, ReturnSerialization, Diverged = ()>:
     Serve<ArgsDeserialization, ReturnSerialization, Diverged>
 {
-    type Method: FnOnce(&mut Self::State, Self::Args) -> Option<Self::Return>;
+    type Method: FnOnce(&mut Self::State, Self::Args<'_>) -> Option<Self::Return>;
 
     fn serve<OuterType>(
         //
         access: fn(&mut OuterType) -> &mut Self::State,
         method: Self::Method,
     ) where
-        OuterType: BorshDeserialize + BorshSerialize;
+        OuterType: StateRead + StateWrite;
     fn extern_serve<OuterType>(access: fn(&mut OuterType) -> &mut Self::State)
     where
-        OuterType: BorshDeserialize + BorshSerialize;
+        OuterType: StateRead + StateWrite;
     fn extern_serve_identity() {
         Self::extern_serve::<Self::State>(|identity| identity)
     }
@@ -102,49 +152,95 @@ pub trait ServeRefMut<ArgsDeserialization, ReturnSerialization, Diverged = ()>:
 pub trait ServeRef<ArgsDeserialization, ReturnSerialization, Diverged = ()>:
     Serve<ArgsDeserialization, ReturnSerialization, Diverged>
 {
-    type Method: FnOnce(&Self::State, Self::Args) -> Option<Self::Return>;
+    type Method: FnOnce(&Self::State, Self::Args<'_>) -> Option<Self::Return>;
 
     fn serve<OuterType>(
         //
         access: fn(&OuterType) -> &Self::State,
         method: Self::Method,
     ) where
-        OuterType: BorshDeserialize;
+        OuterType: StateRead;
     fn extern_serve<OuterType>(_access: fn(&OuterType) -> &Self::State)
     where
-        OuterType: BorshDeserialize;
+        OuterType: StateRead;
     fn extern_serve_identity() {
         Self::extern_serve::<Self::State>(|identity| identity)
     }
 }
 
-pub trait ServeOwned<ArgsDeserialization, Diverged = ()>:
-    Serve<ArgsDeserialization, crate::Borsh, Diverged>
+/// Splits the value returned by an owned-`self` method into the new state
+/// and the value that is returned to the caller.
+///
+/// Methods returning `(Self, T)` get this for free. A method can also
+/// return a designated struct, as long as it implements this trait and
+/// the method is set with `#[contract(split)]`.
+pub trait SplitState<State> {
+    type Value;
+    fn split_state(self) -> (State, Self::Value);
+}
+
+impl<State, Value> SplitState<State> for (State, Value) {
+    type Value = Value;
+    fn split_state(self) -> (State, Value) {
+        self
+    }
+}
+
+pub trait ServeOwned<ArgsDeserialization, ReturnSerialization, Diverged = ()>:
+    Serve<ArgsDeserialization, ReturnSerialization, Diverged>
 {
-    type Method: FnOnce(Self::State, Self::Args) -> Self::State;
+    /// The method gives back the new state and, optionally,
+    /// a value to be returned to the caller.
+    type Method: FnOnce(Self::State, Self::Args<'_>) -> (Self::State, Option<Self::Return>);
 
     fn serve<OuterType>(
         //
         access: fn(&mut OuterType) -> &mut Self::State,
         method: Self::Method,
     ) where
-        OuterType: BorshDeserialize + BorshSerialize;
-    // TODO: since the fn consumes OuterType, it will need to be cloned
-    // for OuterType to still be replaced.
-    //
-    // hope for the best (that the compiler will optimize the clone away)
+        OuterType: StateRead + StateWrite;
     fn extern_serve<OuterType>(access: fn(&mut OuterType) -> &mut Self::State)
     where
-        OuterType: BorshDeserialize + BorshSerialize;
+        OuterType: StateRead + StateWrite;
     fn extern_serve_identity() {
         Self::extern_serve::<Self::State>(|identity| identity)
     }
+
+    /// Moves the state out of `contract`, gives it to `method` and then
+    /// moves the new state back into `contract`.
+    ///
+    /// Works like `std::mem::replace`, but without requiring either a
+    /// placeholder value nor a `Clone` of the state.
+    fn replace_state_with<OuterType, R>(
+        contract: OuterType,
+        access: fn(&mut OuterType) -> &mut Self::State,
+        method: impl FnOnce(Self::State) -> (Self::State, R),
+    ) -> (OuterType, R) {
+        replace_with(contract, access, method)
+    }
+}
+
+/// The implementation of [`ServeOwned::replace_state_with`].
+fn replace_with<OuterType, State, R>(
+    contract: OuterType,
+    access: fn(&mut OuterType) -> &mut State,
+    method: impl FnOnce(State) -> (State, R),
+) -> (OuterType, R) {
+    // if `method` panics, the contract is never dropped,
+    // so the moved-out state can't be dropped a second time
+    let mut contract = std::mem::ManuallyDrop::new(contract);
+    let state: *mut State = access(&mut contract);
+    // safety: `state` points into `contract`, which is alive and
+    // not otherwise accessed until the new state is written back
+    let (new_state, ret) = method(unsafe { std::ptr::read(state) });
+    unsafe { std::ptr::write(state, new_state) };
+    (std::mem::ManuallyDrop::into_inner(contract), ret)
 }
 
 pub trait ServeStateless<ArgsDeserialization, ReturnSerialization, Diverged = ()>:
     Serve<ArgsDeserialization, ReturnSerialization, Diverged>
 {
-    type Method: FnOnce(Self::Args) -> Option<Self::Return>;
+    type Method: FnOnce(Self::Args<'_>) -> Option<Self::Return>;
 
     fn serve(method: Self::Method);
     fn extern_serve();
@@ -156,15 +252,37 @@ pub trait ServeStateless<ArgsDeserialization, ReturnSerialization, Diverged = ()
 pub trait ServeStatelessInit<ArgsDeserialization, Diverged = ()>:
     Serve<ArgsDeserialization, crate::Borsh, Diverged>
 {
-    type Method: FnOnce(Self::Args) -> Self::State;
+    type Method: FnOnce(Self::Args<'_>) -> Self::State;
+
+    fn serve<OuterType>(method: Self::Method)
+    where
+        OuterType: StateWrite,
+        Self::State: Into<OuterType>;
+    fn extern_serve<OuterType>()
+    where
+        OuterType: StateWrite,
+        Self::State: Into<OuterType>;
+    fn extern_serve_identity() {
+        Self::extern_serve::<Self::State>()
+    }
+}
+
+/// Migrates the old state, as it is read from the storage,
+/// into a new state that is then written back.
+pub trait ServeStatelessMigrate<ArgsDeserialization, Diverged = ()>:
+    Serve<ArgsDeserialization, crate::Borsh, Diverged>
+{
+    /// The previous layout of the state.
+    type OldState: BorshDeserialize;
+    type Method: FnOnce(Self::OldState, Self::Args<'_>) -> Self::State;
 
     fn serve<OuterType>(method: Self::Method)
     where
-        OuterType: BorshSerialize,
+        OuterType: StateWrite,
         Self::State: Into<OuterType>;
     fn extern_serve<OuterType>()
     where
-        OuterType: BorshSerialize,
+        OuterType: StateWrite,
         Self::State: Into<OuterType>;
     fn extern_serve_identity() {
         Self::extern_serve::<Self::State>()
@@ -228,6 +346,15 @@ pub mod request {
             method_call.args(args)
         }
 
+        /// Changes the arguments, such as for setting the ones that
+        /// have default values.
+        ///
+        /// eg. `.map_args(|args| args.with_memo(memo))`.
+        pub fn map_args(mut self, f: impl FnOnce(Args) -> Args) -> Self {
+            self.args = f(self.args);
+            self
+        }
+
         pub fn send_amount(self, send_amount: Balance) -> AmountRequest<Args, ArgsSerialization> {
             AmountRequest {
                 method_name: self.method_name,
@@ -307,7 +434,7 @@ pub mod request {
             self
         }
 
-        pub fn request(self) {
+        pub fn request(self) -> near_sdk::Promise {
             near_sdk::Promise::new(self.contract_being_called).function_call(
                 self.method_name.to_string(),
                 self.args
@@ -315,7 +442,77 @@ pub mod request {
                     .expect("Failed to serialize the cross contract args."),
                 self.send_amount,
                 self.prepaid_gas,
-            );
+            )
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::replace_with;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Drops = Rc<RefCell<Vec<&'static str>>>;
+
+    /// Records it's name when dropped.
+    struct Tracked(&'static str, Drops);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    struct Contract {
+        state: Tracked,
+        other: Tracked,
+    }
+
+    fn contract(drops: &Drops) -> Contract {
+        Contract {
+            state: Tracked("old", Rc::clone(drops)),
+            other: Tracked("other", Rc::clone(drops)),
+        }
+    }
+
+    #[test]
+    fn test_replace_with() {
+        let drops = Drops::default();
+        let new_drops = Rc::clone(&drops);
+        let (contract, ret) = replace_with(
+            contract(&drops),
+            |c| &mut c.state,
+            |old| {
+                assert_eq!(old.0, "old");
+                drop(old);
+                (Tracked("new", new_drops), 7)
+            },
+        );
+        assert_eq!(ret, 7);
+        assert_eq!(contract.state.0, "new");
+        assert_eq!(contract.other.0, "other");
+        assert_eq!(*drops.borrow(), ["old"]);
+
+        drop(contract);
+        assert_eq!(*drops.borrow(), ["old", "new", "other"]);
+    }
+
+    #[test]
+    fn test_replace_with_panic() {
+        let drops = Drops::default();
+        let contract = contract(&drops);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            replace_with(
+                contract,
+                |c| &mut c.state,
+                |_old| -> (Tracked, ()) { panic!("the method failed") },
+            )
+        }));
+        assert!(result.is_err());
+
+        // the old state is dropped once, by the method, and the rest of
+        // the contract is leaked instead of dropping the old state again
+        assert_eq!(*drops.borrow(), ["old"]);
+    }
+}
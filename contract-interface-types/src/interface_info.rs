@@ -0,0 +1,232 @@
+//! Information about the methods served by a contract, so that clients
+//! can discover it's capabilities.
+//!
+//! See [`extern_contract_interface!`](crate::extern_contract_interface),
+//! and also [`MethodInfo`] and [`TraitInfo`] for the same information
+//! at compile-time.
+
+use crate::{Json, ToBytes};
+use near_sdk::{
+    env,
+    json_types::{U128, U64},
+    serde::Serialize,
+};
+
+/// How a method accesses the state.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum ReceiverKind {
+    /// `&mut self`.
+    RefMut,
+    /// `&self`.
+    Ref,
+    /// `self` or `mut self`.
+    Owned,
+    /// No `self`.
+    Stateless,
+    /// No `self`, and the method returns the initial state.
+    StatelessInit,
+    /// No `self`, and the method converts the old state into a new one.
+    StatelessMigrate,
+}
+
+impl ReceiverKind {
+    /// Whether the method can't change the state, and so can be
+    /// called as a view.
+    pub fn is_view(&self) -> bool {
+        matches!(self, ReceiverKind::Ref | ReceiverKind::Stateless)
+    }
+}
+
+/// Compile-time information about a method, implemented by it's
+/// `Serve` structure for each implementation made with `#[contract]`.
+///
+/// As with [`Serve`](crate::Serve), `Diverged` is the `_Diverger` of the
+/// implementation's module, so that it can also be implemented for
+/// traits from other crates.
+///
+/// eg. `<impl_counter::increment::Serve as MethodInfo<impl_counter::increment::_Diverger>>::METHOD_NAME`.
+pub trait MethodInfo<Diverged = ()> {
+    /// The name that the method is exported as.
+    ///
+    /// This is without any prefix for `serve::Serve`, and includes it
+    /// for a `serve::ServeAs` that is given a prefixed [`ExportedName`](crate::ExportedName).
+    const METHOD_NAME: &'static str;
+    /// The name of the trait that declares the method.
+    const TRAIT_NAME: &'static str;
+    /// How the method accesses the state.
+    const RECEIVER_KIND: ReceiverKind;
+    /// Whether the method accepts deposits.
+    const PAYABLE: bool;
+    /// Whether the method can only be called by the contract itself.
+    const PRIVATE: bool;
+    /// Whether the method creates the initial state.
+    const INIT: bool;
+    /// The names of the arguments, in order.
+    const ARG_NAMES: &'static [&'static str];
+    /// The types of the arguments, as they are written in the `Args`
+    /// structure.
+    const ARG_TYPES: &'static [&'static str];
+    /// The type of the value returned to the caller, if any.
+    const RETURN_TYPE: Option<&'static str>;
+}
+
+/// Compile-time information about a trait, implemented by the `Info`
+/// structure of the module generated by `#[contract]` on the trait.
+///
+/// eg. `<counter::Info as TraitInfo>::METHODS`.
+pub trait TraitInfo {
+    /// The name of the trait.
+    const TRAIT_NAME: &'static str;
+    /// The names of the methods declared by the trait, in order,
+    /// not including the ones from it's supertraits.
+    const METHODS: &'static [&'static str];
+}
+
+/// Information about a served method.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MethodInterface {
+    /// The exported name of the method.
+    pub name: String,
+    /// The name of the trait that declares the method.
+    #[serde(rename = "trait")]
+    pub trait_name: &'static str,
+    /// How the method accesses the state.
+    pub receiver: ReceiverKind,
+    /// Whether the method can be called as a view.
+    pub view: bool,
+    /// Whether the method accepts deposits.
+    pub payable: bool,
+    /// Whether the method can only be called by the contract itself.
+    pub private: bool,
+    /// The serialization format of the arguments.
+    pub args_format: &'static str,
+    /// The serialization format of the returned value.
+    pub return_format: &'static str,
+    /// The arguments, in order.
+    pub args: Vec<ArgInterface>,
+    /// The type of the returned value, if any.
+    pub return_type: Option<&'static str>,
+    /// The gas that clients should attach by default, if declared.
+    pub default_gas: Option<U64>,
+    /// The deposit that clients should attach by default, if declared.
+    pub default_deposit: Option<U128>,
+}
+
+/// Information about an argument of a served method.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ArgInterface {
+    pub name: &'static str,
+    /// The type as it's written in the `Args` structure.
+    #[serde(rename = "type")]
+    pub type_name: &'static str,
+    /// Whether the argument has a default value, declared with
+    /// `#[contract(default)]`, and so may be missing from the input.
+    pub default: bool,
+}
+
+impl ArgInterface {
+    /// Pairs the names with their types, and whether they have
+    /// default values.
+    pub fn from_names_types(
+        names: &[&'static str],
+        types: &[&'static str],
+        defaults: &[bool],
+    ) -> Vec<Self> {
+        names
+            .iter()
+            .zip(types)
+            .zip(defaults)
+            .map(|((name, type_name), default)| ArgInterface {
+                name,
+                type_name,
+                default: *default,
+            })
+            .collect()
+    }
+}
+
+/// The interface returned by the `__contract_interface` view method.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractInterface {
+    pub methods: Vec<MethodInterface>,
+}
+
+impl ContractInterface {
+    /// Returns the interface, serialized as json, as the method's output.
+    pub fn serve(&self) {
+        env::setup_panic_hook();
+        let bytes =
+            <Self as ToBytes<Json>>::to_bytes(self).expect("Failed to serialize the return value.");
+        env::value_return(&bytes);
+    }
+}
+
+/// Collects the methods from the `methods()` function of each module
+/// generated by `#[contract]` on an impl, which includes the methods
+/// of it's supertraits, into a `Vec<MethodInterface>`.
+///
+/// If the `extern_*!` macro of an impl was called with a `prefix`,
+/// the same prefix should be set here.
+///
+/// A method is listed once even if more than one of the impls has
+/// it, such as a supertrait's impl that is also given directly.
+///
+/// eg.
+/// ```ignore
+/// let methods = contract_interface::contract_methods!(
+///     impl_mods = [super::impl_fungible_token, super::impl_counter(prefix = "a_")]
+/// );
+/// ```
+#[macro_export]
+macro_rules! contract_methods {
+    (
+        impl_mods = [$($($impl_mod:ident)::+ $((prefix = $prefix:literal))?),* $(,)?]
+    ) => {{
+        #[allow(unused_mut)]
+        let mut methods: Vec<$crate::interface_info::MethodInterface> = vec![];
+        $(
+            for method in $($impl_mod::)+methods().into_iter().map(
+                #[allow(unused_mut)]
+                |mut method: $crate::interface_info::MethodInterface| {
+                    $(method.name = format!("{}{}", $prefix, method.name);)?
+                    method
+                },
+            ) {
+                if !methods.iter().any(|m| m.name == method.name) {
+                    methods.push(method);
+                }
+            }
+        )*
+        methods
+    }};
+}
+
+/// Generates the `__contract_interface` view method.
+///
+/// The `impl_mods` are the same as for
+/// [`contract_methods!`](crate::contract_methods).
+///
+/// eg.
+/// ```ignore
+/// contract_interface::extern_contract_interface!(
+///     impl_mods = [super::impl_fungible_token, super::impl_counter(prefix = "a_")]
+/// );
+/// ```
+#[macro_export]
+macro_rules! extern_contract_interface {
+    (
+        impl_mods = [$($($impl_mod:ident)::+ $((prefix = $prefix:literal))?),* $(,)?]
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn __contract_interface() {
+            let methods = $crate::contract_methods!(
+                impl_mods = [$($($impl_mod)::+ $((prefix = $prefix))?),*]
+            );
+            $crate::interface_info::ContractInterface { methods }.serve();
+        }
+    };
+}
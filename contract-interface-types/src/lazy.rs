@@ -0,0 +1,216 @@
+//! States where each top-level field is stored under it's own key.
+//!
+//! See [`Lazy`] and [`LazyField`].
+
+use crate::state::{StateRead, StateWrite};
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+};
+
+/// A top-level field of a state, stored under it's own key.
+///
+/// Can be implemented for each field with `#[contract(lazy_fields)]`.
+pub trait StateField {
+    /// The type of the field.
+    type Value: BorshDeserialize + BorshSerialize;
+
+    /// The storage key of the field.
+    const KEY: &'static [u8];
+}
+
+/// A state that has each of it's fields stored under their own key.
+///
+/// Can be implemented with `#[contract(lazy_fields)]`.
+pub trait LazyFields: Sized {
+    /// Reads all of the fields, or `None` if they were not yet written.
+    fn read_fields() -> Option<Self>;
+
+    /// Writes all of the fields.
+    fn write_fields(&self);
+}
+
+/// Reads a single field, or `None` if it was not yet written.
+pub fn field_read<Field>() -> Option<Field::Value>
+where
+    Field: StateField,
+{
+    env::storage_read(Field::KEY).map(|bytes| {
+        Field::Value::try_from_slice(&bytes)
+            .unwrap_or_else(|_| env::panic_str("Cannot deserialize the contract state."))
+    })
+}
+
+/// Writes a single field.
+pub fn field_write<Field>(value: &Field::Value)
+where
+    Field: StateField,
+{
+    let bytes = value
+        .try_to_vec()
+        .unwrap_or_else(|_| env::panic_str("Cannot serialize the contract state."));
+    env::storage_write(Field::KEY, &bytes);
+}
+
+/// Stores the `State` with each field under it's own key.
+///
+/// Reads and writes all of the fields, so it's used when a method
+/// accesses the whole state, such as init methods.
+/// The `STATE` key is also written (without a value), so that it can
+/// still be known whether the contract was already initialized.
+///
+/// eg. `stored_type = Lazy<Contract>, state_access = state.state`.
+pub struct Lazy<State> {
+    pub state: State,
+}
+
+impl<State> From<State> for Lazy<State> {
+    fn from(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl<State> StateRead for Lazy<State>
+where
+    State: LazyFields,
+{
+    fn state_read() -> Option<Self> {
+        State::read_fields().map(|state| Self { state })
+    }
+}
+
+impl<State> StateWrite for Lazy<State>
+where
+    State: LazyFields,
+{
+    fn state_write(&self) {
+        env::storage_write(b"STATE", &[]);
+        self.state.write_fields();
+    }
+}
+
+/// Stores only a single field of a state, which is read and written
+/// independently of the other fields.
+///
+/// Used when a method only accesses that field.
+///
+/// eg. `stored_type = LazyField<contract::fields::token>, state_access = state.value`.
+pub struct LazyField<Field>
+where
+    Field: StateField,
+{
+    pub value: Field::Value,
+}
+
+impl<Field> StateRead for LazyField<Field>
+where
+    Field: StateField,
+{
+    fn state_read() -> Option<Self> {
+        field_read::<Field>().map(|value| Self { value })
+    }
+}
+
+impl<Field> StateWrite for LazyField<Field>
+where
+    Field: StateField,
+{
+    fn state_write(&self) {
+        field_write::<Field>(&self.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lazy, LazyField, LazyFields, StateField};
+    use crate::state::{StateRead, StateWrite};
+    use near_sdk::borsh::BorshSerialize;
+    use near_sdk::{env, test_utils::VMContextBuilder, testing_env};
+
+    #[derive(Debug, PartialEq)]
+    struct Contract {
+        count: u32,
+        owner: String,
+    }
+
+    #[allow(non_camel_case_types)]
+    struct count;
+
+    #[allow(non_camel_case_types)]
+    struct owner;
+
+    impl StateField for count {
+        type Value = u32;
+        const KEY: &'static [u8] = b"STATE.count";
+    }
+
+    impl StateField for owner {
+        type Value = String;
+        const KEY: &'static [u8] = b"STATE.owner";
+    }
+
+    impl LazyFields for Contract {
+        fn read_fields() -> Option<Self> {
+            Some(Self {
+                count: super::field_read::<count>()?,
+                owner: super::field_read::<owner>()?,
+            })
+        }
+
+        fn write_fields(&self) {
+            super::field_write::<count>(&self.count);
+            super::field_write::<owner>(&self.owner);
+        }
+    }
+
+    fn contract() -> Contract {
+        Contract {
+            count: 1,
+            owner: "alice".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_lazy() {
+        testing_env!(VMContextBuilder::new().build());
+        assert!(Lazy::<Contract>::state_read().is_none());
+
+        Lazy::from(contract()).state_write();
+        // the `STATE` key only marks the contract as initialized
+        assert_eq!(env::storage_read(b"STATE"), Some(vec![]));
+        assert_eq!(
+            env::storage_read(b"STATE.count"),
+            Some(1u32.try_to_vec().unwrap())
+        );
+        assert_eq!(Lazy::<Contract>::state_read().unwrap().state, contract());
+    }
+
+    #[test]
+    fn test_lazy_field() {
+        testing_env!(VMContextBuilder::new().build());
+        assert!(LazyField::<count>::state_read().is_none());
+
+        Lazy::from(contract()).state_write();
+        let mut field = LazyField::<count>::state_read().unwrap();
+        assert_eq!(field.value, 1);
+
+        // only the field's own key is written
+        field.value = 2;
+        field.state_write();
+        assert_eq!(
+            env::storage_read(b"STATE.count"),
+            Some(2u32.try_to_vec().unwrap())
+        );
+        assert_eq!(
+            env::storage_read(b"STATE.owner"),
+            Some("alice".try_to_vec().unwrap())
+        );
+        assert_eq!(
+            Lazy::<Contract>::state_read().unwrap().state,
+            Contract {
+                count: 2,
+                owner: "alice".to_string(),
+            }
+        );
+    }
+}
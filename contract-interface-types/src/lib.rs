@@ -1,7 +1,35 @@
+#[cfg(feature = "schemars")]
+pub mod abi;
 mod byte_args;
+pub mod event;
+pub mod handle_result;
 mod interface;
+pub mod interface_info;
+pub mod lazy;
+#[cfg(feature = "schemars")]
+pub mod schema;
+pub mod source_metadata;
+pub mod state;
+pub mod typescript;
+pub mod versioned;
 
-pub use byte_args::{borsh, json, Borsh, FromBytes, Json, ToBytes};
+#[cfg(feature = "schemars")]
+pub use abi::AbiRoot;
+pub use byte_args::{
+    borsh, json, raw, Borsh, FormatName, FromBorrowedBytes, FromBytes, Json, JsonPathError,
+    JsonPositional, Raw, ToBytes,
+};
+pub use event::{Event, EventLog, EventMetadata};
 pub use interface::{
-    request, Request, Serve, ServeOwned, ServeRef, ServeRefMut, ServeStateless, ServeStatelessInit,
+    request, ExportedName, Request, Serve, ServeOwned, ServeRef, ServeRefMut, ServeStateless,
+    ServeStatelessInit, ServeStatelessMigrate, SplitState,
+};
+pub use interface_info::{
+    ArgInterface, ContractInterface, MethodInfo, MethodInterface, ReceiverKind, TraitInfo,
 };
+pub use lazy::{Lazy, LazyField, LazyFields, StateField};
+#[cfg(feature = "schemars")]
+pub use schemars;
+pub use source_metadata::{ContractSourceMetadata, Standard};
+pub use state::{StateRead, StateWrite};
+pub use versioned::{Versioned, VersionedState};
@@ -0,0 +1,133 @@
+//! JSON Schemas for the `Args` and `Return` structures of methods,
+//! enabled by the `schemars` feature.
+//!
+//! As the types from `near-sdk` don't implement
+//! [`JsonSchema`](schemars::JsonSchema), `#[contract]` replaces them by
+//! the types of this module when deriving the schemas.
+//! eg. `Option<U128>` is described as `Option<schema::U128>`.
+//!
+//! See also [`TraitSchema`].
+
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{
+    InstanceType, Metadata, ObjectValidation, RootSchema, Schema, SchemaObject, StringValidation,
+};
+use schemars::JsonSchema;
+
+/// Describes a type that is serialized as a json string.
+fn string_schema(description: &str, pattern: Option<&str>) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_string()),
+            ..Default::default()
+        })),
+        string: pattern.map(|pattern| {
+            Box::new(StringValidation {
+                pattern: Some(pattern.to_string()),
+                ..Default::default()
+            })
+        }),
+        ..Default::default()
+    }
+    .into()
+}
+
+macro_rules! string_schemas {
+    ($($name:ident: $description:literal $(, pattern = $pattern:literal)?;)*) => {
+        $(
+            #[doc = $description]
+            pub struct $name;
+
+            impl JsonSchema for $name {
+                fn schema_name() -> String {
+                    stringify!($name).to_string()
+                }
+
+                fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+                    #[allow(unused_mut, unused_assignments)]
+                    let mut pattern = None;
+                    $(pattern = Some($pattern);)?
+                    string_schema($description, pattern)
+                }
+            }
+        )*
+    };
+}
+
+string_schemas! {
+    AccountId: "The id of an account, such as `alice.near`.";
+    U128: "A `u128` written as a base-10 string.", pattern = "^[0-9]+$";
+    U64: "A `u64` written as a base-10 string.", pattern = "^[0-9]+$";
+    I128: "An `i128` written as a base-10 string.", pattern = "^-?[0-9]+$";
+    I64: "An `i64` written as a base-10 string.", pattern = "^-?[0-9]+$";
+    Gas: "A quantity of gas written as a base-10 string.", pattern = "^[0-9]+$";
+    Base64VecU8: "Bytes encoded as base64.";
+    PublicKey: "A public key, such as `ed25519:..`.";
+}
+
+/// The schemas of all the methods of a trait, built by the
+/// `json_schema()` function generated in the trait's module.
+///
+/// The root schema is an object that has a property for each method,
+/// which in turn has the `args` and (if json) the `return` schemas.
+pub struct TraitSchema {
+    title: &'static str,
+    gen: SchemaGenerator,
+    methods: ObjectValidation,
+}
+
+impl TraitSchema {
+    pub fn new(title: &'static str) -> Self {
+        Self {
+            title,
+            gen: SchemaSettings::draft07().into_generator(),
+            methods: ObjectValidation::default(),
+        }
+    }
+
+    /// Adds a method that doesn't return a json value.
+    pub fn method<Args: JsonSchema>(self, name: &str) -> Self {
+        self.insert::<Args>(name, None)
+    }
+
+    /// Adds a method that returns a json value.
+    pub fn method_with_return<Args: JsonSchema, Return: JsonSchema>(mut self, name: &str) -> Self {
+        let ret = self.gen.subschema_for::<Return>();
+        self.insert::<Args>(name, Some(ret))
+    }
+
+    fn insert<Args: JsonSchema>(mut self, name: &str, ret: Option<Schema>) -> Self {
+        let mut method = ObjectValidation::default();
+        method
+            .properties
+            .insert("args".to_string(), self.gen.subschema_for::<Args>());
+        method.required.insert("args".to_string());
+        if let Some(ret) = ret {
+            method.properties.insert("return".to_string(), ret);
+        }
+        let method = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(method)),
+            ..Default::default()
+        };
+        self.methods
+            .properties
+            .insert(name.to_string(), method.into());
+        self
+    }
+
+    pub fn into_root_schema(self) -> RootSchema {
+        let mut root = self.gen.into_root_schema_for::<()>();
+        root.schema = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            metadata: Some(Box::new(Metadata {
+                title: Some(self.title.to_string()),
+                ..Default::default()
+            })),
+            object: Some(Box::new(self.methods)),
+            ..Default::default()
+        };
+        root
+    }
+}
@@ -0,0 +1,99 @@
+//! Contract source metadata, as defined by [NEP-330].
+//!
+//! See [`extern_contract_source_metadata!`](crate::extern_contract_source_metadata).
+//!
+//! [NEP-330]: https://nomicon.io/Standards/SourceMetadata
+
+use crate::{Json, ToBytes};
+use near_sdk::{env, serde::Serialize};
+
+/// A standard that is implemented by a contract.
+///
+/// Can be set on traits with
+/// `#[contract(standard = "nep141", version = "1.0.0")]`.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Standard {
+    /// The name of the standard, eg. `"nep141"`.
+    pub standard: &'static str,
+    /// The version of the standard, eg. `"1.0.0"`.
+    pub version: &'static str,
+}
+
+/// The metadata returned by the `contract_source_metadata` view method.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractSourceMetadata {
+    /// The version of the contract's crate.
+    pub version: Option<String>,
+    /// A link to the contract's source code.
+    pub link: Option<String>,
+    /// The standards implemented by the contract.
+    pub standards: Vec<Standard>,
+}
+
+impl ContractSourceMetadata {
+    /// Creates the metadata, where repeated standards are ignored.
+    pub fn new(version: Option<String>, link: Option<String>, standards: Vec<Standard>) -> Self {
+        let mut unique: Vec<Standard> = vec![];
+        for standard in standards {
+            if !unique.contains(&standard) {
+                unique.push(standard);
+            }
+        }
+        Self {
+            version,
+            link,
+            standards: unique,
+        }
+    }
+
+    /// Returns the metadata, serialized as json, as the method's output.
+    pub fn serve(&self) {
+        env::setup_panic_hook();
+        let bytes =
+            <Self as ToBytes<Json>>::to_bytes(self).expect("Failed to serialize the return value.");
+        env::value_return(&bytes);
+    }
+}
+
+/// Generates the `contract_source_metadata` view method.
+///
+/// The standards are collected from the `standards()` function of each
+/// module generated by `#[contract]` on an impl, which includes the
+/// standards of it's trait and supertraits.  
+/// The version is taken from the `CARGO_PKG_VERSION` of the crate
+/// calling this macro.
+///
+/// eg.
+/// ```ignore
+/// contract_interface::extern_contract_source_metadata!(
+///     link = "https://github.com/org/repo",
+///     impl_mods = [super::impl_fungible_token, super::impl_storage]
+/// );
+/// ```
+#[macro_export]
+macro_rules! extern_contract_source_metadata {
+    (
+        $(link = $link:expr,)?
+        impl_mods = [$($($impl_mod:ident)::+),* $(,)?]
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn contract_source_metadata() {
+            #[allow(unused_mut, unused_assignments)]
+            let mut link: Option<String> = None;
+            $(link = Some($link.to_string());)?
+
+            #[allow(unused_mut)]
+            let mut standards = vec![];
+            $(standards.extend($($impl_mod::)+standards());)*
+
+            $crate::source_metadata::ContractSourceMetadata::new(
+                Some(env!("CARGO_PKG_VERSION").to_string()),
+                link,
+                standards,
+            )
+            .serve();
+        }
+    };
+}
@@ -0,0 +1,40 @@
+//! How the contract is read from and written into the storage.
+//!
+//! By default, the contract is stored as a whole with borsh,
+//! under the `STATE` key. See [`Lazy`](crate::Lazy) for a layout
+//! where each field is stored under it's own key.
+
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+};
+
+/// A stored type that can be read from the storage.
+pub trait StateRead: Sized {
+    /// Reads the stored type, or `None` if it was not yet written.
+    fn state_read() -> Option<Self>;
+}
+
+/// A stored type that can be written into the storage.
+pub trait StateWrite {
+    /// Writes the stored type, replacing what was previously stored.
+    fn state_write(&self);
+}
+
+impl<T> StateRead for T
+where
+    T: BorshDeserialize,
+{
+    fn state_read() -> Option<Self> {
+        env::state_read()
+    }
+}
+
+impl<T> StateWrite for T
+where
+    T: BorshSerialize,
+{
+    fn state_write(&self) {
+        env::state_write(self)
+    }
+}
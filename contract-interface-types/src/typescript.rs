@@ -0,0 +1,565 @@
+//! TypeScript bindings for [near-api-js](https://github.com/near/near-api-js),
+//! generated from the methods served by a contract.
+//!
+//! eg. from a test:
+//! ```ignore
+//! let methods = contract_interface::contract_methods!(
+//!     impl_mods = [cs::ft::core_impl::impl_fungible_token, crate::storage::impl_storage]
+//! );
+//! std::fs::write("bindings.ts", contract_interface::typescript::typescript(&methods)).unwrap();
+//! ```
+//!
+//! With the `schemars` feature, [`typescript_with_schemas`] also declares
+//! the structures and enums from the json schemas of the implementations,
+//! eg. `impl_fungible_token::json_schemas()`.
+
+use crate::interface_info::MethodInterface;
+use near_sdk::serde_json::{self, Value};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// The types from `near-sdk` that are serialized as strings.
+const STRING_ALIASES: &[(&str, &str)] = &[
+    ("AccountId", "The id of an account, such as `alice.near`."),
+    ("U128", "A `u128` written as a base-10 string."),
+    ("U64", "A `u64` written as a base-10 string."),
+    ("I128", "An `i128` written as a base-10 string."),
+    ("I64", "An `i64` written as a base-10 string."),
+    ("Gas", "A quantity of gas written as a base-10 string."),
+    ("Base64VecU8", "Bytes encoded as base64."),
+    ("PublicKey", "A public key, such as `ed25519:..`."),
+];
+
+/// The functions that call the methods, where the integers that don't
+/// fit in a `number` are `bigint`s, which `JSON` can't write nor read.
+const PRELUDE: &str = r#"/** Writes the args as json, where bigints are written as numbers. */
+function stringify(args: object): Buffer {
+  const json = JSON.stringify(args, (_key, value) =>
+    typeof value === "bigint" ? `__bigint:${value}` : value
+  );
+  return Buffer.from(json.replace(/"__bigint:(-?\d+)"/g, "$1"));
+}
+
+/**
+ * Reads the returned json, where the integers that don't fit in a
+ * `number` are read as bigints.
+ */
+function parse(bytes: Uint8Array): any {
+  const json = Buffer.from(bytes).toString();
+  if (json === "") {
+    return undefined;
+  }
+  return JSON.parse(json, (_key: string, value: any, context?: { source: string }) =>
+    typeof value === "number" &&
+    !Number.isSafeInteger(value) &&
+    context !== undefined &&
+    /^-?\d+$/.test(context.source)
+      ? BigInt(context.source)
+      : value
+  );
+}
+
+function view(account: Account, contractId: string, methodName: string, args: object): Promise<any> {
+  return account.viewFunction({ contractId, methodName, args, parse, stringify });
+}
+
+async function call(
+  account: Account,
+  contractId: string,
+  methodName: string,
+  args: object,
+  gas?: string,
+  amount?: string
+): Promise<any> {
+  const outcome = await account.functionCall({
+    contractId,
+    methodName,
+    args,
+    stringify,
+    gas: gas === undefined ? undefined : new BN(gas),
+    attachedDeposit: amount === undefined ? undefined : new BN(amount),
+  });
+  const status = outcome.status as FinalExecutionStatus;
+  if (typeof status === "object" && typeof status.SuccessValue === "string") {
+    return parse(Buffer.from(status.SuccessValue, "base64"));
+  }
+  return undefined;
+}"#;
+
+/// Generates a class for each trait, in the order that their methods
+/// appear, where view methods are called with only their arguments and
+/// change methods may also set the gas and deposit, which default to
+/// the ones declared with `#[contract(default_gas = .., default_deposit = ..)]`.
+///
+/// Arguments that are `Option`s or that have default values, declared
+/// with `#[contract(default)]`, are optional. Integers of 64 and 128 bits
+/// are `bigint`s, although the returned ones that fit in a `number` are
+/// read as numbers.
+///
+/// Private methods, and methods that don't take json args, are not included.
+/// Types that are not known, such as structs, are declared as `any`.
+pub fn typescript(methods: &[MethodInterface]) -> String {
+    Generator::default().typescript(methods)
+}
+
+/// As [`typescript`], where the structures and enums that have a
+/// definition in the schemas, such as the ones from
+/// `impl_fungible_token::json_schemas()`, are declared as interfaces
+/// and types instead of `any`.
+#[cfg(feature = "schemars")]
+pub fn typescript_with_schemas(
+    methods: &[MethodInterface],
+    schemas: &[schemars::schema::RootSchema],
+) -> String {
+    let mut definitions = BTreeMap::new();
+    for (name, definition) in schemas.iter().flat_map(|schema| &schema.definitions) {
+        definitions.entry(name.clone()).or_insert_with(|| {
+            serde_json::to_value(definition).expect("Failed to serialize a schema.")
+        });
+    }
+    Generator {
+        definitions,
+        ..Default::default()
+    }
+    .typescript(methods)
+}
+
+/// Collects the declarations of the types as they are used.
+#[derive(Default)]
+struct Generator {
+    /// The json schemas of the types, by name.
+    definitions: BTreeMap<String, Value>,
+    /// The declarations of the types, by name, which are `None` while
+    /// they are being generated, so that recursive types end.
+    declarations: BTreeMap<String, Option<String>>,
+}
+
+impl Generator {
+    fn typescript(mut self, methods: &[MethodInterface]) -> String {
+        let mut traits: Vec<(&str, Vec<&MethodInterface>)> = vec![];
+        for method in methods
+            .iter()
+            .filter(|m| !m.private && m.args_format == "json")
+        {
+            match traits
+                .iter_mut()
+                .find(|(name, _)| *name == method.trait_name)
+            {
+                Some((_, trait_methods)) => trait_methods.push(method),
+                None => traits.push((method.trait_name, vec![method])),
+            }
+        }
+
+        let mut body = String::new();
+        for (trait_name, trait_methods) in traits {
+            for method in &trait_methods {
+                if method.args.is_empty() {
+                    continue;
+                }
+                writeln!(body, "export interface {} {{", args_interface(method)).unwrap();
+                for arg in &method.args {
+                    let optional = if is_optional(arg) { "?" } else { "" };
+                    let ty = self.ts_type(arg.type_name);
+                    writeln!(body, "  {}{}: {};", arg.name, optional, ty).unwrap();
+                }
+                writeln!(body, "}}\n").unwrap();
+            }
+
+            writeln!(body, "/** The methods of `{}`. */", trait_name).unwrap();
+            writeln!(body, "export class {} {{", trait_name).unwrap();
+            writeln!(
+                body,
+                "  constructor(readonly account: Account, readonly contractId: string) {{}}"
+            )
+            .unwrap();
+
+            for method in &trait_methods {
+                let ret = match (method.return_type, method.return_format) {
+                    (None, _) => "void".to_string(),
+                    (Some(ty), "json") => self.ts_type(ty),
+                    (Some(_), _) => "unknown".to_string(),
+                };
+                let all_optional = method.args.iter().all(is_optional);
+                let (args_param, args) = if method.args.is_empty() {
+                    (String::new(), "{}")
+                } else if all_optional {
+                    (format!("args: {} = {{}}", args_interface(method)), "args")
+                } else {
+                    (format!("args: {}", args_interface(method)), "args")
+                };
+                writeln!(body).unwrap();
+                if method.view {
+                    writeln!(
+                        body,
+                        "  {}({}): Promise<{}> {{",
+                        method.name, args_param, ret
+                    )
+                    .unwrap();
+                    writeln!(
+                        body,
+                        "    return view(this.account, this.contractId, \"{}\", {});",
+                        method.name, args
+                    )
+                    .unwrap();
+                } else {
+                    let gas = match method.default_gas {
+                        Some(gas) => format!("gas: string = \"{}\"", gas.0),
+                        None => "gas?: string".to_string(),
+                    };
+                    let amount = match method.default_deposit {
+                        Some(deposit) => format!("amount: string = \"{}\"", deposit.0),
+                        None => "amount?: string".to_string(),
+                    };
+                    let params = [args_param, gas, amount]
+                        .iter()
+                        .filter(|p| !p.is_empty())
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    writeln!(body, "  {}({}): Promise<{}> {{", method.name, params, ret).unwrap();
+                    writeln!(
+                        body,
+                        "    return call(this.account, this.contractId, \"{}\", {}, gas, amount);",
+                        method.name, args
+                    )
+                    .unwrap();
+                }
+                writeln!(body, "  }}").unwrap();
+            }
+            writeln!(body, "}}\n").unwrap();
+        }
+
+        let mut ts = String::new();
+        writeln!(
+            ts,
+            "// Generated by contract-interface from the served methods."
+        )
+        .unwrap();
+        writeln!(ts, "// Changes made by hand will be lost.\n").unwrap();
+        writeln!(ts, "import BN from \"bn.js\";").unwrap();
+        writeln!(ts, "import {{ Account }} from \"near-api-js\";").unwrap();
+        writeln!(
+            ts,
+            "import {{ FinalExecutionStatus }} from \"near-api-js/lib/providers\";\n"
+        )
+        .unwrap();
+        writeln!(ts, "{}\n", PRELUDE).unwrap();
+        for declaration in self.declarations.values().flatten() {
+            writeln!(ts, "{}\n", declaration).unwrap();
+        }
+        ts.push_str(body.trim_end());
+        ts.push('\n');
+        ts
+    }
+
+    /// Converts a Rust type, as written in the source code, into the
+    /// TypeScript type of it's json representation.
+    fn ts_type(&mut self, rust_type: &str) -> String {
+        let ty = rust_type.trim();
+
+        // references, such as `&'a mut str`
+        if let Some(rest) = ty.strip_prefix('&') {
+            let rest = rest.trim_start();
+            let rest = match rest.strip_prefix('\'') {
+                Some(lifetime) => lifetime.split_once(' ').map_or("", |(_, rest)| rest),
+                None => rest,
+            };
+            let rest = rest.trim_start();
+            let rest = rest.strip_prefix("mut ").unwrap_or(rest);
+            return self.ts_type(rest);
+        }
+        // tuples, such as `(AccountId, U128)`
+        if ty.starts_with('(') && ty.ends_with(')') {
+            let items = split_top_level(&ty[1..ty.len() - 1], ',');
+            if items.is_empty() {
+                return "null".to_string();
+            }
+            let items = items
+                .into_iter()
+                .map(|item| self.ts_type(item))
+                .collect::<Vec<_>>();
+            return format!("[{}]", items.join(", "));
+        }
+        // arrays and slices, such as `[u8; 32]`
+        if ty.starts_with('[') && ty.ends_with(']') {
+            let inner = split_top_level(&ty[1..ty.len() - 1], ';');
+            let item = inner.first().copied().unwrap_or("");
+            return array(self.ts_type(item));
+        }
+
+        let generics = match (ty.find('<'), ty.ends_with('>')) {
+            (Some(i), true) => split_top_level(&ty[i + 1..ty.len() - 1], ','),
+            _ => vec![],
+        };
+        let mut generic = |i: usize| {
+            generics
+                .get(i)
+                .map(|g| self.ts_type(g))
+                .unwrap_or_else(|| "unknown".to_string())
+        };
+        let name = path_name(ty);
+        match name {
+            "bool" => "boolean".to_string(),
+            "u8" | "u16" | "u32" | "usize" | "i8" | "i16" | "i32" | "isize" | "f32" | "f64" => {
+                "number".to_string()
+            }
+            "u64" | "u128" | "i64" | "i128" | "Balance" | "Timestamp" | "BlockHeight"
+            | "StorageUsage" => "bigint".to_string(),
+            "String" | "str" | "char" => "string".to_string(),
+            "Option" => format!("{} | null", generic(0)),
+            "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => array(generic(0)),
+            "HashMap" | "BTreeMap" => format!("Record<string, {}>", generic(1)),
+            "Box" | "Rc" | "Arc" | "PromiseOrValue" => generic(0),
+            _ => self.named(name),
+        }
+    }
+
+    /// Declares a named type, from it's definition if there is one.
+    fn named(&mut self, name: &str) -> String {
+        if !self.declarations.contains_key(name) {
+            self.declarations.insert(name.to_string(), None);
+            let alias_doc = STRING_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map(|(_, doc)| *doc);
+            let declaration = match (alias_doc, self.definitions.get(name).cloned()) {
+                (Some(doc), _) => format!("/** {} */\nexport type {} = string;", doc, name),
+                (None, Some(schema)) => self.definition(name, &schema),
+                (None, None) => format!(
+                    "/** The Rust type `{}`, which is not described. */\nexport type {} = any;",
+                    name, name
+                ),
+            };
+            self.declarations
+                .insert(name.to_string(), Some(declaration));
+        }
+        name.to_string()
+    }
+
+    /// An interface for structures, or otherwise a type alias.
+    fn definition(&mut self, name: &str, schema: &Value) -> String {
+        let doc = match schema.get("description").and_then(Value::as_str) {
+            Some(description) => doc_comment(description, ""),
+            None => format!("/** The Rust type `{}`. */", name),
+        };
+        match schema.get("properties").and_then(Value::as_object) {
+            Some(properties) => {
+                let mut interface = format!("{}\nexport interface {} {{\n", doc, name);
+                for (field, field_schema) in properties {
+                    if let Some(description) =
+                        field_schema.get("description").and_then(Value::as_str)
+                    {
+                        writeln!(interface, "  {}", doc_comment(description, "  ")).unwrap();
+                    }
+                    let optional = match schema.as_object() {
+                        Some(schema) if is_required(schema, field) => "",
+                        _ => "?",
+                    };
+                    let ty = self.schema_type(field_schema);
+                    writeln!(interface, "  {}{}: {};", field, optional, ty).unwrap();
+                }
+                interface.push('}');
+                interface
+            }
+            None => format!(
+                "{}\nexport type {} = {};",
+                doc,
+                name,
+                self.schema_type(schema)
+            ),
+        }
+    }
+
+    /// Converts a json schema into the TypeScript type that it describes.
+    fn schema_type(&mut self, schema: &Value) -> String {
+        let schema = match schema {
+            Value::Object(schema) => schema,
+            Value::Bool(false) => return "never".to_string(),
+            _ => return "any".to_string(),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.named(reference.rsplit('/').next().unwrap_or(reference));
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            return union(values.iter().map(Value::to_string).collect());
+        }
+        if let Some(value) = schema.get("const") {
+            return value.to_string();
+        }
+        for key in &["anyOf", "oneOf"] {
+            if let Some(variants) = schema.get(*key).and_then(Value::as_array) {
+                let variants = variants.iter().map(|v| self.schema_type(v)).collect();
+                return union(variants);
+            }
+        }
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            let items = all_of
+                .iter()
+                .map(|item| self.schema_type(item))
+                .collect::<Vec<_>>();
+            return items.join(" & ");
+        }
+
+        match schema.get("type") {
+            Some(Value::Array(instance_types)) => {
+                let types = instance_types
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|t| self.instance_type(t, schema))
+                    .collect();
+                union(types)
+            }
+            Some(Value::String(instance_type)) => self.instance_type(instance_type, schema),
+            _ if schema.contains_key("properties") => self.instance_type("object", schema),
+            _ => "any".to_string(),
+        }
+    }
+
+    /// The TypeScript type of one of the json types of a schema.
+    fn instance_type(
+        &mut self,
+        instance_type: &str,
+        schema: &serde_json::Map<String, Value>,
+    ) -> String {
+        match instance_type {
+            "null" => "null".to_string(),
+            "boolean" => "boolean".to_string(),
+            "string" => "string".to_string(),
+            "number" => "number".to_string(),
+            "integer" => match schema.get("format").and_then(Value::as_str) {
+                Some("uint64" | "int64" | "uint128" | "int128") => "bigint".to_string(),
+                _ => "number".to_string(),
+            },
+            "array" => match schema.get("items") {
+                Some(Value::Array(items)) => {
+                    let items = items
+                        .iter()
+                        .map(|item| self.schema_type(item))
+                        .collect::<Vec<_>>();
+                    format!("[{}]", items.join(", "))
+                }
+                Some(item) => array(self.schema_type(item)),
+                None => "any[]".to_string(),
+            },
+            "object" => match schema.get("properties").and_then(Value::as_object) {
+                Some(properties) => {
+                    let fields = properties
+                        .iter()
+                        .map(|(field, field_schema)| {
+                            let optional = if is_required(schema, field) { "" } else { "?" };
+                            format!("{}{}: {}", field, optional, self.schema_type(field_schema))
+                        })
+                        .collect::<Vec<_>>();
+                    format!("{{ {} }}", fields.join("; "))
+                }
+                None => match schema.get("additionalProperties") {
+                    Some(value @ Value::Object(_)) => {
+                        format!("Record<string, {}>", self.schema_type(value))
+                    }
+                    _ => "Record<string, any>".to_string(),
+                },
+            },
+            _ => "any".to_string(),
+        }
+    }
+}
+
+/// Whether an argument can be left out.
+fn is_optional(arg: &crate::interface_info::ArgInterface) -> bool {
+    arg.default || path_name(arg.type_name) == "Option"
+}
+
+/// Whether a field of an object schema is required.
+fn is_required(schema: &serde_json::Map<String, Value>, field: &str) -> bool {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .any(|f| f == field)
+}
+
+/// A documentation comment, where the lines after the first one are
+/// indented.
+fn doc_comment(doc: &str, indent: &str) -> String {
+    let mut lines = doc.lines();
+    let first = lines.next().unwrap_or_default();
+    let rest = lines.collect::<Vec<_>>();
+    if rest.is_empty() {
+        return format!("/** {} */", first);
+    }
+    let mut comment = format!("/**\n{} * {}", indent, first);
+    for line in rest {
+        write!(comment, "\n{} * {}", indent, line).unwrap();
+    }
+    write!(comment, "\n{} */", indent).unwrap();
+    comment
+}
+
+/// The union of the types, without repeated ones.
+fn union(types: Vec<String>) -> String {
+    let mut unique: Vec<String> = vec![];
+    for ty in types {
+        if !unique.contains(&ty) {
+            unique.push(ty);
+        }
+    }
+    match unique.len() {
+        0 => "never".to_string(),
+        _ => unique.join(" | "),
+    }
+}
+
+/// The name of the interface of the arguments, eg. `FtTransferArgs`.
+fn args_interface(method: &MethodInterface) -> String {
+    let mut name = String::new();
+    for word in method.name.split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name.push_str("Args");
+    name
+}
+
+/// The last segment of a path, without it's generics.
+/// eg. `std::option::Option<String>` -> `Option`.
+fn path_name(rust_type: &str) -> &str {
+    let path = match rust_type.find('<') {
+        Some(i) => &rust_type[..i],
+        None => rust_type,
+    };
+    path.rsplit("::").next().unwrap_or(path).trim()
+}
+
+/// Splits on the commas that are not inside of brackets.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts.into_iter().filter(|p| !p.is_empty()).collect()
+}
+
+/// An array of the type, with parenthesis if it's an union.
+fn array(item: String) -> String {
+    if item.contains('|') {
+        format!("({})[]", item)
+    } else {
+        format!("{}[]", item)
+    }
+}
@@ -0,0 +1,153 @@
+//! States that are stored together with their version.
+//!
+//! See [`VersionedState`] and [`Versioned`].
+
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use std::io;
+
+/// A state that is stored together with it's version, so it can be
+/// upgraded from any older version when read.
+///
+/// Can be implemented with `#[contract(state_versions(V1, V2, Current))]`,
+/// where each version is upgraded into the next one by it's `From`
+/// conversion.
+pub trait VersionedState: BorshSerialize + Sized {
+    /// All of the versions of the state, from the oldest to the latest,
+    /// as a borsh enum.
+    type Versions: BorshDeserialize;
+
+    /// The enum variant index of the latest version, which is `Self`.
+    const LATEST_VERSION: u8;
+
+    /// Upgrades any version into the latest one.
+    fn upgrade(versions: Self::Versions) -> Self;
+}
+
+/// Stores the `State` as the latest of it's versions.
+///
+/// When used as the stored type, older versions are upgraded as the
+/// contract is read by the [`Serve`](crate::Serve) state helpers, such as
+/// [`Serve::state_read_or_panic()`](crate::Serve::state_read_or_panic),
+/// and the latest version is always persisted by
+/// [`Serve::state_write()`](crate::Serve::state_write).
+///
+/// eg. `stored_type = Versioned<Contract>, state_access = state.state`.
+pub struct Versioned<State> {
+    pub state: State,
+}
+
+impl<State> From<State> for Versioned<State> {
+    fn from(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl<State> BorshDeserialize for Versioned<State>
+where
+    State: VersionedState,
+{
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        let versions = State::Versions::deserialize(buf)?;
+        Ok(Self {
+            state: State::upgrade(versions),
+        })
+    }
+}
+
+impl<State> BorshSerialize for Versioned<State>
+where
+    State: VersionedState,
+{
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        // same layout as the latest variant of the `Versions` enum
+        State::LATEST_VERSION.serialize(writer)?;
+        self.state.serialize(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Versioned, VersionedState};
+    use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+    struct V1 {
+        count: u8,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+    struct V2 {
+        count: u32,
+        owner: String,
+    }
+
+    impl From<V1> for V2 {
+        fn from(v1: V1) -> Self {
+            Self {
+                count: v1.count.into(),
+                owner: "unknown".to_string(),
+            }
+        }
+    }
+
+    #[derive(BorshDeserialize)]
+    enum Versions {
+        V1(V1),
+        V2(V2),
+    }
+
+    impl VersionedState for V2 {
+        type Versions = Versions;
+        const LATEST_VERSION: u8 = 1;
+        fn upgrade(versions: Versions) -> Self {
+            match versions {
+                Versions::V1(v1) => v1.into(),
+                Versions::V2(v2) => v2,
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let state = V2 {
+            count: 7,
+            owner: "alice".to_string(),
+        };
+        let bytes = Versioned::from(state).try_to_vec().unwrap();
+        // tagged as the latest version
+        assert_eq!(bytes[0], 1);
+        assert_eq!(&bytes[1..], (7u32, "alice").try_to_vec().unwrap());
+
+        let versioned = Versioned::<V2>::try_from_slice(&bytes).unwrap();
+        assert_eq!(
+            versioned.state,
+            V2 {
+                count: 7,
+                owner: "alice".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_upgrade() {
+        // stored by an older contract, tagged as the first version
+        let mut bytes = vec![0];
+        bytes.extend(V1 { count: 3 }.try_to_vec().unwrap());
+
+        let versioned = Versioned::<V2>::try_from_slice(&bytes).unwrap();
+        assert_eq!(
+            versioned.state,
+            V2 {
+                count: 3,
+                owner: "unknown".to_string(),
+            }
+        );
+
+        // and is then written back as the latest version
+        let bytes = versioned.try_to_vec().unwrap();
+        assert_eq!(bytes[0], 1);
+
+        // an unknown version is rejected
+        assert!(Versioned::<V2>::try_from_slice(&[2, 0]).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use near_sdk::json_types::U128;
 use near_sdk::AccountId;
 use near_sdk::PromiseOrValue;
 
-#[contract]
+#[contract(standard = "nep141", version = "1.0.0")]
 pub trait FungibleTokenCore {
     /// Transfers positive `amount` of tokens from the `env::predecessor_account_id` to `receiver_id`.
     /// Both accounts must be registered with the contract for transfer to succeed. (See [NEP-145](https://github.com/near/NEPs/discussions/145))
@@ -15,6 +15,7 @@ pub trait FungibleTokenCore {
     /// - `receiver_id` - the account ID of the receiver.
     /// - `amount` - the amount of tokens to transfer. Must be a positive number in decimal string representation.
     /// - `memo` - an optional string field in a free form to associate a memo with this transfer.
+    #[contract(emits(crate::fungible_token::FtEvent), default_deposit = 1)]
     fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
 
     /// Transfers positive `amount` of tokens from the `env::predecessor_account_id` to `receiver_id` account. Then
@@ -40,6 +41,11 @@ pub trait FungibleTokenCore {
     /// - `msg` - a string message that will be passed to `ft_on_transfer` contract call.
     ///
     /// Returns a promise which will result in the amount of tokens withdrawn from sender's account.
+    #[contract(
+        emits(crate::fungible_token::FtEvent),
+        default_deposit = 1,
+        default_gas = 100_000_000_000_000
+    )]
     fn ft_transfer_call(
         &mut self,
         receiver_id: AccountId,
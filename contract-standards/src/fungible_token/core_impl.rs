@@ -1,12 +1,13 @@
 use crate::fungible_token::core::FungibleTokenCore;
+use crate::fungible_token::events::{FtBurn, FtEvent, FtTransfer};
 use crate::fungible_token::resolver::FungibleTokenResolver;
-use contract_interface::contract;
+use contract_interface::{contract, Event};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::LookupMap;
 use near_sdk::json_types::U128;
 use near_sdk::{
-    assert_one_yocto, env, log, require, AccountId, Balance, Gas, IntoStorageKey, PanicOnDefault,
-    PromiseOrValue, PromiseResult, StorageUsage,
+    assert_one_yocto, env, log, require, AccountId, Balance, Gas, IntoStorageKey, PromiseOrValue,
+    PromiseResult, StorageUsage,
 };
 
 const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(5_000_000_000_000);
@@ -23,7 +24,7 @@ const NO_DEPOSIT: Balance = 0;
 ///     - AccountRegistrar -- interface for an account to register and unregister
 ///
 /// For example usage, see examples/fungible-token/src/lib.rs.
-#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+#[derive(BorshDeserialize, BorshSerialize)]
 pub struct FungibleToken {
     /// AccountID -> Account balance.
     pub accounts: LookupMap<AccountId, Balance>,
@@ -107,9 +108,16 @@ impl FungibleToken {
         self.internal_withdraw(sender_id, amount);
         self.internal_deposit(receiver_id, amount);
         log!("Transfer {} from {} to {}", amount, sender_id, receiver_id);
-        if let Some(memo) = memo {
+        if let Some(memo) = &memo {
             log!("Memo: {}", memo);
         }
+        FtEvent::FtTransfer(vec![FtTransfer {
+            old_owner_id: sender_id.clone(),
+            new_owner_id: receiver_id.clone(),
+            amount: amount.into(),
+            memo,
+        }])
+        .emit();
     }
 
     pub fn internal_register_account(&mut self, account_id: &AccountId) {
@@ -146,12 +154,9 @@ impl FungibleTokenCore for FungibleToken {
         self.internal_transfer(&sender_id, &receiver_id, amount, memo);
 
         // Initiating receiver's call and the callback
-        use crate::fungible_token::receiver;
-        type ExternalReceiverRequest =
-            receiver::fungible_token_receiver::ft_on_transfer::Request<receiver::ExternalReceiver>;
-
-        let receiver_call = ExternalReceiverRequest::contract(receiver_id.clone())
-            .args(sender_id.clone(), amount.into(), msg)
+        use crate::fungible_token::receiver::FungibleTokenReceiverClient;
+        let receiver_call = FungibleTokenReceiverClient::contract(receiver_id.clone())
+            .ft_on_transfer(sender_id.clone(), amount.into(), msg)
             .send_amount(NO_DEPOSIT)
             .prepaid_gas(env::prepaid_gas() - GAS_FOR_FT_TRANSFER_CALL)
             .request();
@@ -218,11 +223,24 @@ impl FungibleToken {
                         receiver_id,
                         sender_id
                     );
+                    FtEvent::FtTransfer(vec![FtTransfer {
+                        old_owner_id: receiver_id,
+                        new_owner_id: sender_id.clone(),
+                        amount: refund_amount.into(),
+                        memo: Some("refund".to_string()),
+                    }])
+                    .emit();
                     return (amount - refund_amount, 0);
                 } else {
                     // Sender's account was deleted, so we need to burn tokens.
                     self.total_supply -= refund_amount;
                     log!("The account of the sender was deleted");
+                    FtEvent::FtBurn(vec![FtBurn {
+                        owner_id: receiver_id,
+                        amount: refund_amount.into(),
+                        memo: Some("refund".to_string()),
+                    }])
+                    .emit();
                     return (amount, refund_amount);
                 }
             }
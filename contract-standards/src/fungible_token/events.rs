@@ -0,0 +1,47 @@
+//! Events of the NEP-141 standard, logged in the NEP-297 format.
+//!
+//! eg. `EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_mint","data":[..]}`.
+
+use contract_interface::contract;
+use near_sdk::json_types::U128;
+use near_sdk::serde::Serialize;
+use near_sdk::AccountId;
+
+/// All of the fungible token events.
+#[contract(event(standard = "nep141", version = "1.0.0"))]
+pub enum FtEvent {
+    FtMint(Vec<FtMint>),
+    FtTransfer(Vec<FtTransfer>),
+    FtBurn(Vec<FtBurn>),
+}
+
+/// Tokens that were created for an account.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtMint {
+    pub owner_id: AccountId,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// Tokens that were transferred between accounts.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtTransfer {
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// Tokens that were destroyed from an account.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtBurn {
+    pub owner_id: AccountId,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
@@ -8,6 +8,7 @@ pub const FT_METADATA_SPEC: &str = "ft-1.0.0";
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Deserialize, Serialize)]
 #[serde(crate = "near_sdk::serde")]
+#[contract(json_schema)]
 pub struct FungibleTokenMetadata {
     pub spec: String,
     pub name: String,
@@ -18,7 +19,7 @@ pub struct FungibleTokenMetadata {
     pub decimals: u8,
 }
 
-#[contract]
+#[contract(standard = "nep148", version = "1.0.0")]
 pub trait FungibleTokenMetadataProvider {
     fn ft_metadata(&self) -> FungibleTokenMetadata;
 }
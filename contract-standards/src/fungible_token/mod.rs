@@ -1,6 +1,7 @@
 pub mod core;
 #[macro_use]
 pub mod core_impl;
+pub mod events;
 pub mod macros;
 pub mod metadata;
 pub mod receiver;
@@ -10,3 +11,4 @@ pub mod storage_impl;
 
 pub use self::core::FungibleTokenCore;
 pub use core_impl::FungibleToken;
+pub use events::FtEvent;
@@ -3,6 +3,7 @@ use near_sdk::{json_types::U128, AccountId};
 
 #[contract]
 pub trait FungibleTokenResolver {
+    #[contract(emits(crate::fungible_token::FtEvent))]
     fn ft_resolve_transfer(
         &mut self,
         sender_id: AccountId,
@@ -1,6 +1,7 @@
+use crate::fungible_token::events::{FtBurn, FtEvent};
 use crate::fungible_token::FungibleToken;
 use crate::storage_management::{StorageBalance, StorageBalanceBounds, StorageManagement};
-use contract_interface::contract;
+use contract_interface::{contract, Event};
 use near_sdk::json_types::U128;
 use near_sdk::{assert_one_yocto, env, log, AccountId, Balance, Promise};
 
@@ -19,6 +20,14 @@ impl FungibleToken {
                 self.accounts.remove(&account_id);
                 self.total_supply -= balance;
                 Promise::new(account_id.clone()).transfer(self.storage_balance_bounds().min.0 + 1);
+                if balance > 0 {
+                    FtEvent::FtBurn(vec![FtBurn {
+                        owner_id: account_id.clone(),
+                        amount: balance.into(),
+                        memo: None,
+                    }])
+                    .emit();
+                }
                 Some((account_id, balance))
             } else {
                 env::panic_str(
@@ -49,6 +58,9 @@ impl FungibleToken {
 )]
 impl StorageManagement for FungibleToken {
     // `registration_only` doesn't affect the implementation for vanilla fungible token.
+    //
+    // already registered accounts don't change the state.
+    #[contract(write_if_changed)]
     #[allow(unused_variables)]
     fn storage_deposit(
         &mut self,
@@ -8,15 +8,6 @@ pub struct Pause<T> {
     pub paused: bool,
 }
 
-impl<T> Default for Pause<T>
-where
-    T: Default,
-{
-    fn default() -> Self {
-        todo!("copy PanicOnDefault behavior")
-    }
-}
-
 impl<Ft> Pause<Ft> {
     pub fn panic_if_paused(&self) {
         use near_sdk::require;
@@ -27,7 +18,7 @@ impl<Ft> Pause<Ft> {
 #[contract(mod = "impl_pause", trait = "pause_core")]
 impl<T> crate::pause::PauseCore for Pause<T>
 where
-    T: Default + BorshSerialize + BorshDeserialize,
+    T: BorshSerialize + BorshDeserialize,
 {
     fn pause(&mut self, p: bool) {
         self.paused = p;
@@ -6,7 +6,7 @@ use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 #[contract(mod = "impl_pause_fungible_token", trait = "fungible_token_core")]
 impl<Ft> FungibleTokenCore for Pause<Ft>
 where
-    Ft: FungibleTokenCore + Default + BorshSerialize + BorshDeserialize,
+    Ft: FungibleTokenCore + BorshSerialize + BorshDeserialize,
 {
     fn ft_transfer(
         &mut self,
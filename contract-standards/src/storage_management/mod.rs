@@ -5,6 +5,7 @@ use near_sdk::AccountId;
 
 #[derive(Serialize)]
 #[serde(crate = "near_sdk::serde")]
+#[contract(json_schema)]
 pub struct StorageBalance {
     pub total: U128,
     pub available: U128,
@@ -12,12 +13,13 @@ pub struct StorageBalance {
 
 #[derive(Serialize)]
 #[serde(crate = "near_sdk::serde")]
+#[contract(json_schema)]
 pub struct StorageBalanceBounds {
     pub min: U128,
     pub max: Option<U128>,
 }
 
-#[contract]
+#[contract(standard = "nep145", version = "1.0.0")]
 pub trait StorageManagement {
     // if `registration_only=true` MUST refund above the minimum balance if the account didn't exist and
     //     refund full deposit if the account exists.
@@ -43,6 +45,7 @@ pub trait StorageManagement {
     ///
     /// Returns the StorageBalance structure showing updated balances.
     // #[contract(payable)]
+    #[contract(default_deposit = 1)]
     fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance;
 
     /// Unregisters the predecessor account and returns the storage NEAR deposit back.
@@ -57,6 +60,7 @@ pub trait StorageManagement {
     /// Returns `true` iff the account was unregistered.
     /// Returns `false` iff account was not registered before.
     // #[contract(payable)]
+    #[contract(default_deposit = 1)]
     fn storage_unregister(&mut self, force: Option<bool>) -> bool;
 
     fn storage_balance_bounds(&self) -> StorageBalanceBounds;
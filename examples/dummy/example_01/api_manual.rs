@@ -28,6 +28,13 @@ pub extern "C" fn method_owned_manual() {
     impl_trait_1::method_owned::Serve::extern_serve(|state| state);
 }
 
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_owned_with_return_manual() {
+    use ci::ServeOwned;
+    impl_trait_1::method_owned_with_return::Serve::extern_serve(|state| state);
+}
+
 // #[cfg(target_arch = "wasm32")]
 #[no_mangle]
 pub extern "C" fn method_stateless_manual() {
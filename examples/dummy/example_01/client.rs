@@ -1,7 +1,8 @@
 //! Shows how a client could use the builder to make
 //! a request into a method of an implementation of a trait.
 //!
-//! One uses the "impl" builder, and the other uses the "trait" builder.
+//! One uses the "impl" builder, another uses the "trait" builder,
+//! and the last one uses the trait's client.
 
 pub fn client_example() {
     // using the "impl" request builder, where the state is known (Struct1)
@@ -12,11 +13,20 @@ pub fn client_example() {
         .prepaid_gas(near_sdk::Gas::from(0))
         .request();
 
-    // using the "trait" request builder, where the state must be informed
-    type StructRequest = super::trait_1::method_ref_mut::Request<super::Struct1>;
-    StructRequest::contract("account.id".parse().unwrap())
+    // using the "trait" request builder, where the state is not needed
+    // since `method_ref_mut` doesn't mention `Self`
+    use super::trait_1::method_ref_mut::Request as TraitRequest;
+    TraitRequest::contract("account.id".parse().unwrap())
         .args(true)
         .send_amount(0)
         .prepaid_gas(near_sdk::Gas::from(0))
         .request();
+
+    // using the trait's client, where the state is not needed
+    // since `Trait1` doesn't mention `Self`
+    let _promise = super::Trait1Client::contract("account.id".parse().unwrap())
+        .method_ref_mut(true)
+        .send_amount(0)
+        .prepaid_gas(near_sdk::Gas::from(0))
+        .request();
 }
@@ -3,17 +3,14 @@
 #![allow(unused_variables)]
 
 use contract_interface::contract;
-use near_sdk::{
-    borsh::{self, BorshDeserialize, BorshSerialize},
-    PanicOnDefault,
-};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 
 pub mod api;
 pub mod api_manual;
 pub mod client;
 
 /// (Struct1 Doc).
-#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault, Clone)]
+#[derive(BorshDeserialize, BorshSerialize)]
 pub struct Struct1 {
     a: u8,
     b: u16,
@@ -44,6 +41,19 @@ pub trait Trait1 {
         unimplemented!()
     }
 
+    /// (method_owned_with_return Doc).
+    ///
+    /// Owned method may also return a value together with `Self`,
+    /// where `Self` will be stored as the state at the end
+    /// and the value will be returned to the caller.
+    #[contract(return_format = "borsh")]
+    fn method_owned_with_return(self, my_bool: bool) -> (Self, u8)
+    where
+        Self: Sized,
+    {
+        unimplemented!()
+    }
+
     /// (method_stateless Doc).
     fn method_stateless(my_bool: bool) {
         unimplemented!()
@@ -90,6 +100,18 @@ impl Trait1 for Struct1 {
         unimplemented!()
     }
 
+    /// (Impl method_owned_with_return Doc).
+    ///
+    /// Owned method may also return a value together with `Self`,
+    /// where `Self` will be stored as the state at the end
+    /// and the value will be returned to the caller.
+    fn method_owned_with_return(self, my_bool: bool) -> (Self, u8)
+    where
+        Self: Sized,
+    {
+        unimplemented!()
+    }
+
     /// (Impl method_stateless Doc).
     fn method_stateless(my_bool: bool) {
         unimplemented!()
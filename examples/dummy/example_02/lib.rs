@@ -8,13 +8,10 @@ pub mod api;
 pub mod api_manual;
 
 use contract_interface::contract;
-use near_sdk::{
-    borsh::{self, BorshDeserialize, BorshSerialize},
-    PanicOnDefault,
-};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 
 /// (Original Struct documentation)
-#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault, Clone)]
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
 pub struct Struct2 {
     a: u8,
     b: u16,
@@ -7,17 +7,14 @@
 
 use contract_standards::cs;
 use cs::ft::FungibleToken;
-use near_sdk::{
-    borsh::{self, BorshDeserialize, BorshSerialize},
-    PanicOnDefault,
-};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 
 pub mod api;
 pub mod api_manual;
 // pub mod client;
 
 /// (Struct1 Doc).
-#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+#[derive(BorshDeserialize, BorshSerialize)]
 pub struct Struct3 {
     token: FungibleToken,
 }
@@ -55,6 +55,27 @@ pub extern "C" fn fn_ref_mut_manual() {
     super::impl_trait_4::fn_ref_mut::Serve::extern_serve();
 }
 
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn fn_str_manual() {
+    use ci::ServeStateless;
+    super::impl_trait_4::fn_str::Serve::extern_serve();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn fn_bytes_manual() {
+    use ci::ServeStateless;
+    super::impl_trait_4::fn_bytes::Serve::extern_serve();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn fn_cow_manual() {
+    use ci::ServeStateless;
+    super::impl_trait_4::fn_cow::Serve::extern_serve();
+}
+
 // #[cfg(target_arch = "wasm32")]
 #[no_mangle]
 pub extern "C" fn fn_tuple_manual() {
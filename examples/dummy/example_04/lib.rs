@@ -1,21 +1,21 @@
 //! A dummy example showing methods that use various kinds of argument types
 //! such as `&T` and such.
+//!
+//! `&str`, `&[u8]` and `Cow` arguments are borrowed from the input,
+//! without being copied.
 
 #![allow(unused_parens)]
 #![allow(unused_variables)]
 
 use contract_interface::contract;
-use near_sdk::{
-    borsh::{self, BorshDeserialize, BorshSerialize},
-    PanicOnDefault,
-};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 
 #[macro_use]
 pub mod api;
 pub mod api_manual;
 
 /// (Original Struct4 documentation)
-#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault, Clone)]
+#[derive(BorshDeserialize, BorshSerialize)]
 pub struct Struct4 {
     a: u8,
     b: u16,
@@ -34,6 +34,9 @@ pub trait Trait4 {
     fn fn_ptr_mut(my_ptr: *mut bool);
     fn fn_ref(my_ref: &bool);
     fn fn_ref_mut(my_ref: &mut bool);
+    fn fn_str(my_str: &str);
+    fn fn_bytes(my_bytes: &[u8]);
+    fn fn_cow(my_cow: std::borrow::Cow<str>);
     // fn fn_slice(my_slice: &[bool]);
     // fn fn_dyn(my_dyn: Box<dyn Clone>);
     fn fn_tuple(my_tuple: (bool, bool));
@@ -77,6 +80,16 @@ impl Trait4 for Struct4 {
         unimplemented!()
     }
 
+    fn fn_str(my_str: &str) {
+        unimplemented!()
+    }
+    fn fn_bytes(my_bytes: &[u8]) {
+        unimplemented!()
+    }
+    fn fn_cow(my_cow: std::borrow::Cow<str>) {
+        unimplemented!()
+    }
+
     // fn fn_slice(my_slice: &[bool]) {
     //     unimplemented!()
     // }
@@ -13,6 +13,13 @@ pub extern "C" fn method_init_manual() {
     super::impl_trait_5::method_init::Serve::extern_serve::<super::Struct5>();
 }
 
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_migrate_manual() {
+    use ci::ServeStatelessMigrate;
+    super::impl_trait_5::method_migrate::Serve::extern_serve::<super::Struct5>();
+}
+
 // #[cfg(target_arch = "wasm32")]
 #[no_mangle]
 pub extern "C" fn method_payable_manual() {
@@ -28,3 +35,12 @@ pub extern "C" fn method_private_manual() {
     use ci::ServeStateless;
     super::impl_trait_5::method_private::Serve::extern_serve();
 }
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_handle_result_manual() {
+    use ci::ServeRef;
+    super::impl_trait_5::method_handle_result::Serve::extern_serve::<super::Struct5>(
+        |state: &super::Struct5| state,
+    );
+}
@@ -5,28 +5,40 @@
 #![allow(unused_variables)]
 
 use contract_interface::contract;
-use near_sdk::{
-    borsh::{self, BorshDeserialize, BorshSerialize},
-    PanicOnDefault,
-};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 
 #[macro_use]
 pub mod api;
 pub mod api_manual;
 
 /// (Original Struct5 documentation)
-#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault, Clone)]
+#[derive(BorshDeserialize, BorshSerialize)]
 pub struct Struct5 {
     a: u8,
 }
 
+/// (Original OldStruct5 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldStruct5 {
+    a: bool,
+}
+
 /// (Trait5 Doc).
 #[contract]
 pub trait Trait5 {
     #[contract(init())]
     fn method_init() -> Self;
+    #[contract(migrate)]
+    fn method_migrate(old: OldStruct5) -> Self;
     fn method_payable(&mut self);
     fn method_private();
+    #[contract(handle_result)]
+    fn method_handle_result(&self, fail: bool) -> Result<u8, String>;
+    #[contract(handle_result(error = "json"))]
+    fn method_handle_result_json(&self, fail: bool) -> Result<u8, Vec<u8>>;
+    /// The arguments are a json array, eg. `[1, "a"]`.
+    #[contract(args_format = "json_positional")]
+    fn method_positional(&self, a: u8, b: &str) -> u8;
 }
 
 /// (Impl Trait5 for Struct5 Doc).
@@ -37,6 +49,11 @@ impl Trait5 for Struct5 {
         unimplemented!()
     }
 
+    #[contract(migrate(from = "OldStruct5"))]
+    fn method_migrate(old: OldStruct5) -> Self {
+        Self { a: old.a as u8 }
+    }
+
     #[contract(payable)]
     fn method_payable(&mut self) {
         unimplemented!()
@@ -46,6 +63,18 @@ impl Trait5 for Struct5 {
     fn method_private() {
         unimplemented!();
     }
+
+    fn method_handle_result(&self, fail: bool) -> Result<u8, String> {
+        unimplemented!();
+    }
+
+    fn method_handle_result_json(&self, fail: bool) -> Result<u8, Vec<u8>> {
+        unimplemented!();
+    }
+
+    fn method_positional(&self, a: u8, b: &str) -> u8 {
+        a + b.len() as u8
+    }
 }
 
 /// Note:  
@@ -0,0 +1,16 @@
+//! Contains `extern "C"` functions, created by macros,
+//! where those macros were created automatically.  
+//! Both the macros and the created functions can be seen in the
+//! geenrated documentation of this project.
+//!
+//! The contract is stored as a [`Versioned`](contract_interface::Versioned)
+//! state, so older versions are upgraded when read.
+//!
+//! See also [`super::api_manual`] for an example of how to
+//! manually create those functions, without using any macros.
+
+crate::macros::extern_impl_trait_6!(
+    stored_type = contract_interface::Versioned<super::Struct6>,
+    state_access = state.state,
+    impl_mod = super::impl_trait_6
+);
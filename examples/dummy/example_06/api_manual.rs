@@ -0,0 +1,32 @@
+//! Contains `extern "C"` functions, manually created.  
+//! The created functions can also be seen in the documentation.
+//!
+//! See also [`super::api`] for an example of how to automatically
+//! create those functions.
+
+use contract_interface::ci;
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_init_manual() {
+    use ci::ServeStatelessInit;
+    super::impl_trait_6::method_init::Serve::extern_serve::<ci::Versioned<super::Struct6>>();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_ref_manual() {
+    use ci::ServeRef;
+    super::impl_trait_6::method_ref::Serve::extern_serve::<ci::Versioned<super::Struct6>>(
+        |contract: &ci::Versioned<super::Struct6>| &contract.state,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_ref_mut_manual() {
+    use ci::ServeRefMut;
+    super::impl_trait_6::method_ref_mut::Serve::extern_serve::<ci::Versioned<super::Struct6>>(
+        |contract: &mut ci::Versioned<super::Struct6>| &mut contract.state,
+    );
+}
@@ -0,0 +1,84 @@
+//! A dummy example showing a state that is stored together with
+//! it's version, and that is upgraded from older versions when read.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[macro_use]
+pub mod api;
+pub mod api_manual;
+
+/// (Original Struct6V1 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct6V1 {
+    a: u8,
+}
+
+/// (Original Struct6V2 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct6V2 {
+    a: u8,
+    b: u16,
+}
+
+/// (Original Struct6 documentation)
+#[contract(state_versions(Struct6V1, Struct6V2, Struct6))]
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct6 {
+    a: u8,
+    b: u16,
+    c: u32,
+}
+
+impl From<Struct6V1> for Struct6V2 {
+    fn from(old: Struct6V1) -> Self {
+        Self { a: old.a, b: 0 }
+    }
+}
+
+impl From<Struct6V2> for Struct6 {
+    fn from(old: Struct6V2) -> Self {
+        Self {
+            a: old.a,
+            b: old.b,
+            c: 0,
+        }
+    }
+}
+
+/// (Trait6 Doc).
+#[contract]
+pub trait Trait6 {
+    #[contract(init())]
+    fn method_init() -> Self;
+    fn method_ref(&self) -> u32;
+    fn method_ref_mut(&mut self, c: u32);
+}
+
+/// (Impl Trait6 for Struct6 Doc).
+#[contract(mod = "impl_trait_6", trait = "trait_6")]
+impl Trait6 for Struct6 {
+    #[contract(init())]
+    fn method_init() -> Self {
+        Self { a: 0, b: 0, c: 0 }
+    }
+
+    fn method_ref(&self) -> u32 {
+        self.c
+    }
+
+    fn method_ref_mut(&mut self, c: u32) {
+        self.c = c;
+    }
+}
+
+/// Note:  
+/// Because of how `#[macro_use]` works, this module must be
+/// at root and must come _after_ the referenced macros
+/// are defined.  
+/// Ie. This should be the last thing at the root of the project.
+pub mod macros {
+    pub use extern_impl_trait_6;
+}
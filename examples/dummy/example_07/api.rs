@@ -0,0 +1,24 @@
+//! Contains `extern "C"` functions, created by macros,
+//! where those macros were created automatically.  
+//! Both the macros and the created functions can be seen in the
+//! geenrated documentation of this project.
+//!
+//! The methods of `Trait7` access the whole contract, which is stored as
+//! [`Lazy`](contract_interface::Lazy) fields, while the methods of
+//! `Counting7` only read and write the
+//! [`LazyField`](contract_interface::LazyField) of the counter.
+//!
+//! See also [`super::api_manual`] for an example of how to
+//! manually create those functions, without using any macros.
+
+crate::macros::extern_impl_trait_7!(
+    stored_type = contract_interface::Lazy<super::Struct7>,
+    state_access = state.state,
+    impl_mod = super::impl_trait_7
+);
+
+crate::macros::extern_impl_counting_7!(
+    stored_type = contract_interface::LazyField<super::struct_7::fields::counter>,
+    state_access = state.value,
+    impl_mod = super::impl_counting_7
+);
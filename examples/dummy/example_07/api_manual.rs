@@ -0,0 +1,41 @@
+//! Contains `extern "C"` functions, manually created.  
+//! The created functions can also be seen in the documentation.
+//!
+//! See also [`super::api`] for an example of how to automatically
+//! create those functions.
+
+use contract_interface::ci;
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_init_manual() {
+    use ci::ServeStatelessInit;
+    super::impl_trait_7::method_init::Serve::extern_serve::<ci::Lazy<super::Struct7>>();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_names_manual() {
+    use ci::ServeRef;
+    super::impl_trait_7::method_names::Serve::extern_serve::<ci::Lazy<super::Struct7>>(
+        |contract: &ci::Lazy<super::Struct7>| &contract.state,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_count_manual() {
+    use ci::ServeRef;
+    super::impl_counting_7::method_count::Serve::extern_serve::<
+        ci::LazyField<super::struct_7::fields::counter>,
+    >(|contract: &ci::LazyField<super::struct_7::fields::counter>| &contract.value);
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_increment_manual() {
+    use ci::ServeRefMut;
+    super::impl_counting_7::method_increment::Serve::extern_serve::<
+        ci::LazyField<super::struct_7::fields::counter>,
+    >(|contract: &mut ci::LazyField<super::struct_7::fields::counter>| &mut contract.value);
+}
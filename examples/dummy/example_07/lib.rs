@@ -0,0 +1,79 @@
+//! A dummy example showing a state that has each of it's fields
+//! stored under their own key, so that methods can read and write
+//! only the field that they access.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[macro_use]
+pub mod api;
+pub mod api_manual;
+
+/// (Original Counter7 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Counter7 {
+    count: u32,
+}
+
+/// (Original Struct7 documentation)
+#[contract(lazy_fields)]
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct7 {
+    counter: Counter7,
+    names: Vec<String>,
+}
+
+/// (Trait7 Doc).
+#[contract]
+pub trait Trait7 {
+    #[contract(init())]
+    fn method_init(names: Vec<String>) -> Self;
+    fn method_names(&self) -> Vec<String>;
+}
+
+/// (Impl Trait7 for Struct7 Doc).
+#[contract(mod = "impl_trait_7", trait = "trait_7")]
+impl Trait7 for Struct7 {
+    #[contract(init())]
+    fn method_init(names: Vec<String>) -> Self {
+        Self {
+            counter: Counter7 { count: 0 },
+            names,
+        }
+    }
+
+    fn method_names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+}
+
+/// (Counting7 Doc).
+#[contract]
+pub trait Counting7 {
+    fn method_count(&self) -> u32;
+    fn method_increment(&mut self);
+}
+
+/// (Impl Counting7 for Counter7 Doc).
+#[contract(mod = "impl_counting_7", trait = "counting_7")]
+impl Counting7 for Counter7 {
+    fn method_count(&self) -> u32 {
+        self.count
+    }
+
+    fn method_increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+/// Note:  
+/// Because of how `#[macro_use]` works, this module must be
+/// at root and must come _after_ the referenced macros
+/// are defined.  
+/// Ie. This should be the last thing at the root of the project.
+pub mod macros {
+    pub use extern_impl_counting_7;
+    pub use extern_impl_trait_7;
+}
@@ -0,0 +1,33 @@
+//! Contains `extern "C"` functions, created by macros,
+//! where those macros were created automatically.  
+//! Both the macros and the created functions can be seen in the
+//! geenrated documentation of this project.
+//!
+//! `Counting8` is served once for each counter, where the exported
+//! names are prefixed by `a_` or `b_`. Each of those is placed in
+//! it's own module.
+//!
+//! See also [`super::api_manual`] for an example of how to
+//! manually create those functions, without using any macros.
+
+crate::macros::extern_impl_trait_8!(stored_type = super::Struct8, impl_mod = super::impl_trait_8);
+
+/// Exports `a_method_count` and `a_method_increment`.
+pub mod a {
+    crate::macros::extern_impl_counting_8!(
+        stored_type = super::super::Struct8,
+        state_access = state.a,
+        impl_mod = super::super::impl_counting_8,
+        prefix = "a_"
+    );
+}
+
+/// Exports `b_method_count` and `b_method_increment`.
+pub mod b {
+    crate::macros::extern_impl_counting_8!(
+        stored_type = super::super::Struct8,
+        state_access = state.b,
+        impl_mod = super::super::impl_counting_8,
+        prefix = "b_"
+    );
+}
@@ -0,0 +1,50 @@
+//! Contains `extern "C"` functions, manually created.  
+//! The created functions can also be seen in the documentation.
+//!
+//! See also [`super::api`] for an example of how to automatically
+//! create those functions.
+
+use contract_interface::ci;
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_init_manual() {
+    use ci::ServeStatelessInit;
+    super::impl_trait_8::method_init::Serve::extern_serve::<super::Struct8>();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn a_method_count_manual() {
+    use ci::ServeRef;
+    super::impl_counting_8::method_count::Serve::extern_serve::<super::Struct8>(
+        |contract: &super::Struct8| &contract.a,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn a_method_increment_manual() {
+    use ci::ServeRefMut;
+    super::impl_counting_8::method_increment::Serve::extern_serve::<super::Struct8>(
+        |contract: &mut super::Struct8| &mut contract.a,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn b_method_count_manual() {
+    use ci::ServeRef;
+    super::impl_counting_8::method_count::Serve::extern_serve::<super::Struct8>(
+        |contract: &super::Struct8| &contract.b,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn b_method_increment_manual() {
+    use ci::ServeRefMut;
+    super::impl_counting_8::method_increment::Serve::extern_serve::<super::Struct8>(
+        |contract: &mut super::Struct8| &mut contract.b,
+    );
+}
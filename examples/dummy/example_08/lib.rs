@@ -0,0 +1,72 @@
+//! A dummy example showing the same implementation being served
+//! for two different fields of the state, under prefixed names.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[macro_use]
+pub mod api;
+pub mod api_manual;
+
+/// (Original Counter8 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Counter8 {
+    count: u32,
+}
+
+/// (Original Struct8 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct8 {
+    a: Counter8,
+    b: Counter8,
+}
+
+/// (Trait8 Doc).
+#[contract]
+pub trait Trait8 {
+    #[contract(init())]
+    fn method_init() -> Self;
+}
+
+/// (Impl Trait8 for Struct8 Doc).
+#[contract(mod = "impl_trait_8", trait = "trait_8")]
+impl Trait8 for Struct8 {
+    #[contract(init())]
+    fn method_init() -> Self {
+        Self {
+            a: Counter8 { count: 0 },
+            b: Counter8 { count: 0 },
+        }
+    }
+}
+
+/// (Counting8 Doc).
+#[contract]
+pub trait Counting8 {
+    fn method_count(&self) -> u32;
+    fn method_increment(&mut self);
+}
+
+/// (Impl Counting8 for Counter8 Doc).
+#[contract(mod = "impl_counting_8", trait = "counting_8")]
+impl Counting8 for Counter8 {
+    fn method_count(&self) -> u32 {
+        self.count
+    }
+
+    fn method_increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+/// Note:  
+/// Because of how `#[macro_use]` works, this module must be
+/// at root and must come _after_ the referenced macros
+/// are defined.  
+/// Ie. This should be the last thing at the root of the project.
+pub mod macros {
+    pub use extern_impl_counting_8;
+    pub use extern_impl_trait_8;
+}
@@ -0,0 +1,14 @@
+//! Contains `extern "C"` functions, created by macros,
+//! where those macros were created automatically.  
+//! Both the macros and the created functions can be seen in the
+//! geenrated documentation of this project.
+//!
+//! Serving `Greeter9` also serves it's supertrait `Named9`.
+//!
+//! See also [`super::api_manual`] for an example of how to
+//! manually create those functions, without using any macros.
+
+crate::macros::extern_impl_greeter_9!(
+    stored_type = super::Struct9,
+    impl_mod = super::impl_greeter_9
+);
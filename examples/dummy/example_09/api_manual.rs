@@ -0,0 +1,32 @@
+//! Contains `extern "C"` functions, manually created.  
+//! The created functions can also be seen in the documentation.
+//!
+//! See also [`super::api`] for an example of how to automatically
+//! create those functions.
+
+use contract_interface::ci;
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_init_manual() {
+    use ci::ServeStatelessInit;
+    super::impl_named_9::method_init::Serve::extern_serve::<super::Struct9>();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_name_manual() {
+    use ci::ServeRef;
+    super::impl_named_9::method_name::Serve::extern_serve::<super::Struct9>(
+        |contract: &super::Struct9| contract,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_greet_manual() {
+    use ci::ServeRef;
+    super::impl_greeter_9::method_greet::Serve::extern_serve::<super::Struct9>(
+        |contract: &super::Struct9| contract,
+    );
+}
@@ -0,0 +1,72 @@
+//! A dummy example showing a trait that is served together with
+//! it's supertrait, by a single macro invocation.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[macro_use]
+pub mod api;
+pub mod api_manual;
+
+/// (Original Struct9 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct9 {
+    name: String,
+}
+
+/// (Named9 Doc).
+#[contract]
+pub trait Named9 {
+    #[contract(init())]
+    fn method_init(name: String) -> Self;
+    fn method_name(&self) -> String;
+}
+
+/// (Greeter9 Doc).
+#[contract]
+pub trait Greeter9: Named9 {
+    fn method_greet(&self) -> String;
+}
+
+/// (Impl Named9 for Struct9 Doc).
+#[contract(mod = "impl_named_9", trait = "named_9")]
+impl Named9 for Struct9 {
+    #[contract(init())]
+    fn method_init(name: String) -> Self {
+        Self { name }
+    }
+
+    fn method_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// (Impl Greeter9 for Struct9 Doc).
+///
+/// The methods of [`Named9`] are also served by
+/// [`extern_impl_greeter_9`].
+#[contract(
+    mod = "impl_greeter_9",
+    trait = "greeter_9",
+    supertrait(
+        extern_macro = "crate::macros::extern_impl_named_9",
+        impl_mod = "crate::impl_named_9"
+    )
+)]
+impl Greeter9 for Struct9 {
+    fn method_greet(&self) -> String {
+        format!("Hello, {}", self.method_name())
+    }
+}
+
+/// Note:  
+/// Because of how `#[macro_use]` works, this module must be
+/// at root and must come _after_ the referenced macros
+/// are defined.  
+/// Ie. This should be the last thing at the root of the project.
+pub mod macros {
+    pub use extern_impl_greeter_9;
+    pub use extern_impl_named_9;
+}
@@ -0,0 +1,29 @@
+//! Shows how a client could use the imported trait's builders to make
+//! requests into the external contract.
+
+use super::{ref_finance, SwapAction};
+use near_sdk::json_types::U128;
+use near_sdk::Gas;
+
+pub fn client_example() {
+    // a view method, whose builder doesn't need the contract's state
+    // as the imported methods don't mention `Self`
+    let _promise = ref_finance::get_pool::Request::contract("ref-finance.near".parse().unwrap())
+        .args(0)
+        .prepaid_gas(Gas::from(5_000_000_000_000))
+        .request();
+
+    // a payable method, whose arguments use the imported types
+    let action = SwapAction {
+        pool_id: 0,
+        token_in: "wrap.near".parse().unwrap(),
+        amount_in: Some(U128(1_000)),
+        token_out: "usdc.near".parse().unwrap(),
+        min_amount_out: U128(1),
+    };
+    let _promise = ref_finance::swap::Request::contract("ref-finance.near".parse().unwrap())
+        .args(vec![action], None)
+        .send_amount(1)
+        .prepaid_gas(Gas::from(30_000_000_000_000))
+        .request();
+}
@@ -0,0 +1,10 @@
+//! A dummy example showing a trait that is imported from the
+//! NEAR ABI document of an external contract, which was not written
+//! with `#[contract]`.
+
+pub mod client;
+
+contract_interface::import_abi!(
+    "examples/dummy/example_10/ref_finance_abi.json",
+    trait = "RefFinance"
+);
@@ -0,0 +1,12 @@
+//! Contains `extern "C"` functions, created by macros,
+//! where those macros were created automatically.  
+//! Both the macros and the created functions can be seen in the
+//! geenrated documentation of this project.
+//!
+//! See also [`super::api_manual`] for an example of how to
+//! manually create those functions, without using any macros.
+
+crate::macros::extern_impl_blobs_11!(
+    stored_type = super::Struct11,
+    impl_mod = super::impl_blobs_11
+);
@@ -0,0 +1,41 @@
+//! Contains `extern "C"` functions, manually created.  
+//! The created functions can also be seen in the documentation.
+//!
+//! See also [`super::api`] for an example of how to automatically
+//! create those functions.
+
+use contract_interface::ci;
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_init_manual() {
+    use ci::ServeStatelessInit;
+    super::impl_blobs_11::method_init::Serve::extern_serve::<super::Struct11>();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_upload_manual() {
+    use ci::ServeRefMut;
+    super::impl_blobs_11::method_upload::Serve::extern_serve::<super::Struct11>(
+        |contract: &mut super::Struct11| contract,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_download_manual() {
+    use ci::ServeRef;
+    super::impl_blobs_11::method_download::Serve::extern_serve::<super::Struct11>(
+        |contract: &super::Struct11| contract,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_echo_manual() {
+    use ci::ServeRef;
+    super::impl_blobs_11::method_echo::Serve::extern_serve::<super::Struct11>(
+        |contract: &super::Struct11| contract,
+    );
+}
@@ -0,0 +1,19 @@
+//! Shows how a client could use the builders to make requests
+//! into methods that take the input bytes as they are.
+
+use near_sdk::Gas;
+
+pub fn client_example() {
+    // the bytes are sent as they are, without being serialized
+    use super::blobs_11::method_upload::Request;
+    let _promise = Request::contract("blobs.near".parse().unwrap())
+        .args(b"file contents".to_vec())
+        .prepaid_gas(Gas::from(5_000_000_000_000))
+        .request();
+
+    // the same happens when using the trait's client
+    let _promise = super::Blobs11Client::contract("blobs.near".parse().unwrap())
+        .method_echo(vec![1, 2, 3])
+        .prepaid_gas(Gas::from(5_000_000_000_000))
+        .request();
+}
@@ -0,0 +1,69 @@
+//! A dummy example showing methods that take the input bytes as they
+//! are, and that return bytes as they are, without serializing them.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[macro_use]
+pub mod api;
+pub mod api_manual;
+pub mod client;
+
+/// (Original Struct11 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct11 {
+    blob: Vec<u8>,
+}
+
+/// (Blobs11 Doc).
+#[contract]
+pub trait Blobs11 {
+    #[contract(init())]
+    fn method_init() -> Self;
+
+    /// Stores the input, such as an uploaded file.
+    #[contract(args_format = "raw")]
+    fn method_upload(&mut self, blob: Vec<u8>);
+
+    /// Returns the stored bytes.
+    #[contract(return_format = "raw")]
+    fn method_download(&self) -> Vec<u8>;
+
+    /// Returns the input back, such as when forwarding a call.
+    ///
+    /// The bytes are borrowed from the input, without being copied.
+    #[contract(args_format = "raw", return_format = "raw")]
+    fn method_echo(&self, bytes: &[u8]) -> Vec<u8>;
+}
+
+/// (Impl Blobs11 for Struct11 Doc).
+#[contract(mod = "impl_blobs_11", trait = "blobs_11")]
+impl Blobs11 for Struct11 {
+    #[contract(init())]
+    fn method_init() -> Self {
+        Self { blob: vec![] }
+    }
+
+    fn method_upload(&mut self, blob: Vec<u8>) {
+        self.blob = blob;
+    }
+
+    fn method_download(&self) -> Vec<u8> {
+        self.blob.clone()
+    }
+
+    fn method_echo(&self, bytes: &[u8]) -> Vec<u8> {
+        bytes.to_vec()
+    }
+}
+
+/// Note:  
+/// Because of how `#[macro_use]` works, this module must be
+/// at root and must come _after_ the referenced macros
+/// are defined.  
+/// Ie. This should be the last thing at the root of the project.
+pub mod macros {
+    pub use extern_impl_blobs_11;
+}
@@ -0,0 +1,41 @@
+//! Contains `extern "C"` functions, manually created.  
+//! The created functions can also be seen in the documentation.
+//!
+//! See also [`super::api`] for an example of how to automatically
+//! create those functions.
+
+use contract_interface::ci;
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_init_manual() {
+    use ci::ServeStatelessInit;
+    super::impl_items_12::method_init::Serve::extern_serve::<super::Struct12>();
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_add_manual() {
+    use ci::ServeRefMut;
+    super::impl_items_12::method_add::Serve::extern_serve::<super::Struct12>(
+        |contract: &mut super::Struct12| contract,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_list_manual() {
+    use ci::ServeRef;
+    super::impl_items_12::method_list::Serve::extern_serve::<super::Struct12>(
+        |contract: &super::Struct12| contract,
+    );
+}
+
+// #[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn method_describe_manual() {
+    use ci::ServeRef;
+    super::impl_items_12::method_describe::Serve::extern_serve::<super::Struct12>(
+        |contract: &super::Struct12| contract,
+    );
+}
@@ -0,0 +1,28 @@
+//! Shows how a client could use the builders to make requests
+//! into methods that have arguments with default values.
+
+use near_sdk::Gas;
+
+pub fn client_example() {
+    // only the arguments without default values are required,
+    // so `{"item":7}` is sent
+    use super::items_12::method_add::Request;
+    let _promise = Request::contract("items.near".parse().unwrap())
+        .args(7)
+        .prepaid_gas(Gas::from(5_000_000_000_000))
+        .request();
+
+    // the others can be set, and then they are also sent
+    let _promise = Request::contract("items.near".parse().unwrap())
+        .args(7)
+        .with_count(3)
+        .prepaid_gas(Gas::from(5_000_000_000_000))
+        .request();
+
+    // the trait's client sets them on the `ClientArgs`
+    let _promise = super::Items12Client::contract("items.near".parse().unwrap())
+        .method_list()
+        .map_args(|args| args.with_limit(5))
+        .prepaid_gas(Gas::from(5_000_000_000_000))
+        .request();
+}
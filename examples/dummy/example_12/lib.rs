@@ -0,0 +1,86 @@
+//! A dummy example showing arguments that have default values, and
+//! methods that reject unknown arguments.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[macro_use]
+pub mod api;
+pub mod api_manual;
+pub mod client;
+
+/// The number of items in a page, when it's not given.
+pub const PAGE_LIMIT: u32 = 10;
+
+/// (Original Struct12 documentation)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Struct12 {
+    items: Vec<u64>,
+}
+
+/// (Items12 Doc).
+///
+/// Every method rejects arguments that it doesn't know about.
+#[contract(strict)]
+pub trait Items12 {
+    #[contract(init())]
+    fn method_init() -> Self;
+
+    /// Adds the item `count` times, once by default.
+    fn method_add(&mut self, item: u64, #[contract(default = "1")] count: u32);
+
+    /// Lists the items, from the first one and up to [`PAGE_LIMIT`]
+    /// of them by default.
+    fn method_list(
+        &self,
+        #[contract(default = "0")] from: u32,
+        #[contract(default = "PAGE_LIMIT")] limit: u32,
+    ) -> Vec<u64>;
+
+    /// Describes the items, with a `"- "` prefix by default.
+    ///
+    /// The default value is also borrowed.
+    fn method_describe(&self, #[contract(default = "\"- \"")] prefix: &str) -> Vec<String>;
+}
+
+/// (Impl Items12 for Struct12 Doc).
+#[contract(mod = "impl_items_12", trait = "items_12")]
+impl Items12 for Struct12 {
+    #[contract(init())]
+    fn method_init() -> Self {
+        Self { items: vec![] }
+    }
+
+    fn method_add(&mut self, item: u64, count: u32) {
+        for _ in 0..count {
+            self.items.push(item);
+        }
+    }
+
+    fn method_list(&self, from: u32, limit: u32) -> Vec<u64> {
+        self.items
+            .iter()
+            .skip(from as usize)
+            .take(limit as usize)
+            .cloned()
+            .collect()
+    }
+
+    fn method_describe(&self, prefix: &str) -> Vec<String> {
+        self.items
+            .iter()
+            .map(|item| format!("{}{}", prefix, item))
+            .collect()
+    }
+}
+
+/// Note:  
+/// Because of how `#[macro_use]` works, this module must be
+/// at root and must come _after_ the referenced macros
+/// are defined.  
+/// Ie. This should be the last thing at the root of the project.
+pub mod macros {
+    pub use extern_impl_items_12;
+}
@@ -31,3 +31,29 @@ crate::macros::extern_impl_storage!(
     stored_type = Contract,
     impl_mod = crate::storage::impl_storage
 );
+
+/// Calls the given macro with the implementations served by this
+/// contract, as it's `impl_mods = [..]`.
+macro_rules! with_impl_mods {
+    ($($macro:ident)::+!($($args:tt)*)) => {
+        $($macro)::+!(
+            $($args)*
+            impl_mods = [
+                crate::impl_contract,
+                cs::ft::core_impl::impl_fungible_token,
+                crate::metadata_provider::impl_ft_metadata_provider,
+                crate::resolver::impl_resolver,
+                crate::storage::impl_storage,
+            ]
+        );
+    };
+}
+
+with_impl_mods!(contract_interface::extern_contract_source_metadata!(
+    link = "https://github.com/chikai-io/contract-interface",
+));
+
+with_impl_mods!(contract_interface::extern_contract_interface!());
+
+#[cfg(feature = "schemars")]
+with_impl_mods!(contract_interface::extern_contract_abi!());
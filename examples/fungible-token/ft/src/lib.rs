@@ -15,7 +15,7 @@ NOTES:
   - To prevent the deployed contract from being modified or deleted, it should not have any access
     keys on its account.
 */
-use contract_interface::contract;
+use contract_interface::{contract, Event};
 use contract_standards::cs;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::LazyOption;
@@ -110,6 +110,12 @@ impl ContractBehaviour for Contract {
         };
         this.token.internal_register_account(&owner_id);
         this.token.internal_deposit(&owner_id, total_supply.into());
+        cs::ft::FtEvent::FtMint(vec![cs::ft::events::FtMint {
+            owner_id,
+            amount: total_supply,
+            memo: Some("Initial tokens supply is minted".to_string()),
+        }])
+        .emit();
         this
     }
 }
@@ -198,4 +204,134 @@ mod tests {
         );
         assert_eq!(contract.token.ft_balance_of(accounts(1)).0, transfer_amount);
     }
+
+    #[cfg(all(feature = "serve", feature = "schemars"))]
+    #[test]
+    fn test_abi() {
+        use contract_interface::abi::{AbiFunctionKind, AbiFunctionModifier};
+        let abi = crate::api::contract_abi();
+
+        let function = |name: &str| {
+            abi.body
+                .functions
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap_or_else(|| panic!("missing function {}", name))
+        };
+
+        let new = function("new");
+        assert_eq!(new.kind, AbiFunctionKind::Call);
+        assert_eq!(new.modifiers, vec![AbiFunctionModifier::Init]);
+        assert!(new.result.is_none());
+
+        let ft_transfer = function("ft_transfer");
+        assert_eq!(ft_transfer.kind, AbiFunctionKind::Call);
+        assert_eq!(ft_transfer.modifiers, vec![AbiFunctionModifier::Payable]);
+        let args = ft_transfer
+            .params
+            .args
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(args, vec!["receiver_id", "amount", "memo"]);
+        let amount =
+            near_sdk::serde_json::to_value(&ft_transfer.params.args[1].type_schema).unwrap();
+        assert_eq!(amount["$ref"], "#/definitions/U128");
+
+        let ft_balance_of = function("ft_balance_of");
+        assert_eq!(ft_balance_of.kind, AbiFunctionKind::View);
+        let result = ft_balance_of.result.as_ref().unwrap();
+        assert_eq!(result.serialization_type, "json");
+        let result = near_sdk::serde_json::to_value(&result.type_schema).unwrap();
+        assert_eq!(result["$ref"], "#/definitions/U128");
+
+        // the references are defined in the root schema
+        let root_schema = near_sdk::serde_json::to_value(&abi.body.root_schema).unwrap();
+        assert_eq!(root_schema["definitions"]["U128"]["type"], "string");
+    }
+
+    #[cfg(feature = "serve")]
+    #[test]
+    fn test_typescript() {
+        let methods = contract_interface::contract_methods!(
+            impl_mods = [
+                cs::ft::core_impl::impl_fungible_token,
+                crate::metadata_provider::impl_ft_metadata_provider,
+                crate::storage::impl_storage,
+            ]
+        );
+        let ts = contract_interface::typescript::typescript(&methods);
+
+        assert!(ts.contains("export class FungibleTokenCore {"));
+        assert!(ts.contains("  memo?: string | null;"));
+        assert!(ts.contains(
+            "  ft_transfer(args: FtTransferArgs, gas?: string, amount: string = \"1\"): Promise<void> {"
+        ));
+        assert!(ts.contains(
+            "  ft_transfer_call(args: FtTransferCallArgs, gas: string = \"100000000000000\", amount: string = \"1\"): Promise<U128> {"
+        ));
+        assert!(ts.contains("  ft_balance_of(args: FtBalanceOfArgs): Promise<U128> {"));
+        assert!(
+            ts.contains("    return view(this.account, this.contractId, \"ft_balance_of\", args);")
+        );
+        // without the schemas, structures are not described
+        assert!(ts.contains("export type StorageBalance = any;"));
+    }
+
+    #[cfg(all(feature = "serve", feature = "schemars"))]
+    #[test]
+    fn test_typescript_with_schemas() {
+        let methods = contract_interface::contract_methods!(
+            impl_mods = [
+                cs::ft::core_impl::impl_fungible_token,
+                crate::metadata_provider::impl_ft_metadata_provider,
+                crate::storage::impl_storage,
+            ]
+        );
+        let mut schemas = cs::ft::core_impl::impl_fungible_token::json_schemas();
+        schemas.extend(crate::metadata_provider::impl_ft_metadata_provider::json_schemas());
+        schemas.extend(crate::storage::impl_storage::json_schemas());
+        let ts = contract_interface::typescript::typescript_with_schemas(&methods, &schemas);
+
+        assert!(ts.contains("export interface StorageBalance {"));
+        assert!(ts.contains("  total: U128;"));
+        assert!(ts.contains("export interface FungibleTokenMetadata {"));
+    }
+
+    #[test]
+    fn test_state_free_request() {
+        use cs::ft::core::fungible_token_core::ft_balance_of;
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+
+        // the server's state is not needed for making the request
+        let _promise = ft_balance_of::Request::contract(accounts(2))
+            .args(accounts(3))
+            .prepaid_gas(near_sdk::Gas::from(5_000_000_000_000))
+            .request();
+
+        // the client's arguments are read as the server's arguments
+        let args = ft_balance_of::ClientArgs {
+            account_id: accounts(3),
+            _phantom: Default::default(),
+        };
+        let args = near_sdk::serde_json::to_vec(&args).unwrap();
+        let args: ft_balance_of::Args<cs::ft::FungibleToken> =
+            near_sdk::serde_json::from_slice(&args).unwrap();
+        assert_eq!(args.account_id, accounts(3));
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        use cs::ft::core::fungible_token_core;
+        let schema = fungible_token_core::json_schema::<cs::ft::FungibleToken>();
+        let schema = near_sdk::serde_json::to_value(&schema).unwrap();
+
+        let ft_transfer = &schema["properties"]["ft_transfer"]["properties"];
+        assert_eq!(ft_transfer["args"]["$ref"], "#/definitions/FtTransferArgs");
+        let args = &schema["definitions"]["FtTransferArgs"];
+        assert_eq!(args["properties"]["amount"]["$ref"], "#/definitions/U128");
+        assert!(ft_transfer.get("return").is_some());
+    }
 }
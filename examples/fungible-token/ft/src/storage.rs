@@ -18,7 +18,7 @@ impl OnAccountClosed for Contract {}
     trait = "cs::storage::storage_management"
 )]
 impl cs::storage::StorageManagement for Contract {
-    #[contract(payable)]
+    #[contract(payable, write_if_changed)]
     fn storage_deposit(
         &mut self,
         account_id: Option<AccountId>,
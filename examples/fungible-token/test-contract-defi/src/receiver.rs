@@ -36,7 +36,7 @@ impl cs::ft::receiver::FungibleTokenReceiver for DeFi {
             msg
         );
 
-        type SelfRequest = crate::value_return::value_return_trait::value_please::Request<DeFi>;
+        use crate::value_return::value_return_trait::value_please::Request as SelfRequest;
         match msg.as_str() {
             "take-my-money" => PromiseOrValue::Value(U128::from(0)),
             _ => SelfRequest::contract(env::current_account_id())
@@ -4,10 +4,18 @@
 // https://github.com/bkchr/proc-macro-crate/issues/10#issuecomment-826386235
 extern crate self as contract_interface;
 
-pub use contract_interface_macros::contract;
+pub use contract_interface_macros::{contract, import_abi};
+pub use contract_interface_types::typescript;
+#[cfg(feature = "schemars")]
+pub use contract_interface_types::{abi, extern_contract_abi, schema, schemars, AbiRoot};
 pub use contract_interface_types::{
-    borsh, json, request, Borsh, FromBytes, Json, Request, Serve, ServeOwned, ServeRef,
-    ServeRefMut, ServeStateless, ServeStatelessInit, ToBytes,
+    borsh, contract_methods, event, extern_contract_interface, extern_contract_source_metadata,
+    handle_result, interface_info, json, lazy, raw, request, source_metadata, ArgInterface, Borsh,
+    ContractInterface, ContractSourceMetadata, Event, EventLog, EventMetadata, ExportedName,
+    FormatName, FromBorrowedBytes, FromBytes, Json, JsonPathError, JsonPositional, Lazy, LazyField,
+    LazyFields, MethodInfo, MethodInterface, Raw, ReceiverKind, Request, Serve, ServeOwned,
+    ServeRef, ServeRefMut, ServeStateless, ServeStatelessInit, ServeStatelessMigrate, SplitState,
+    Standard, StateField, StateRead, StateWrite, ToBytes, TraitInfo, Versioned, VersionedState,
 };
 
 // aliases
@@ -0,0 +1,53 @@
+//! The `Args` of methods with defaulted args, which can be missing from
+//! the input, and that reject unknown fields when strict.
+
+use contract_interface::contract;
+
+#[contract(strict)]
+pub trait ArgsDefaults {
+    fn method(
+        &self,
+        a: u8,
+        #[contract(default = "7")] b: u8,
+        #[contract(default = "\"x\"")] c: &str,
+    );
+}
+
+pub struct Defaults;
+
+impl ArgsDefaults for Defaults {
+    fn method(&self, _a: u8, _b: u8, _c: &str) {}
+}
+
+#[test]
+fn test_args_defaults() {
+    use args_defaults::method;
+    type Args = method::Args<'static, Defaults>;
+    let args: Args = near_sdk::serde_json::from_str(r#"{"a":1}"#).unwrap();
+    assert_eq!((args.a, args.b, &*args.c), (1, 7, "x"));
+    let args: Args = near_sdk::serde_json::from_str(r#"{"a":1,"b":2,"c":"y"}"#).unwrap();
+    assert_eq!((args.a, args.b, &*args.c), (1, 2, "y"));
+
+    // strict args reject unknown fields
+    let err = near_sdk::serde_json::from_str::<Args>(r#"{"a":1,"d":2}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().starts_with("unknown field `d`"));
+
+    // clients only send the args that are set
+    let args = method::ClientArgs {
+        a: 1,
+        b: None,
+        c: None,
+        _phantom: Default::default(),
+    };
+    assert_eq!(
+        near_sdk::serde_json::to_string(&args).unwrap(),
+        r#"{"a":1}"#
+    );
+    let args = args.with_c("y".to_string());
+    assert_eq!(
+        near_sdk::serde_json::to_string(&args).unwrap(),
+        r#"{"a":1,"c":"y"}"#
+    );
+}
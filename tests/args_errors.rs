@@ -0,0 +1,101 @@
+//! The errors of the args read from the input, which show the name
+//! that the method is exported as and the path of the failing argument.
+
+#![allow(unused_variables)]
+
+use contract_interface::{contract, ExportedName, FromBorrowedBytes, Json, Serve};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{test_utils::VMContextBuilder, testing_env};
+
+#[contract]
+pub trait Errors {
+    fn method(receiver_id: String, amount: u8);
+
+    fn method_no_args();
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct State;
+
+#[contract(mod = "impl_errors", trait = "errors")]
+impl Errors for State {
+    fn method(receiver_id: String, amount: u8) {}
+
+    fn method_no_args() {}
+}
+
+/// Exports `a_method` and `a_method_no_args`, which read their
+/// args as [`impl_errors::method::ServeAs`] does for [`Prefixed`].
+pub mod a {
+    extern_impl_errors!(
+        stored_type = super::State,
+        impl_mod = super::impl_errors,
+        prefix = "a_"
+    );
+}
+
+type Method = impl_errors::method::Serve;
+type MethodNoArgs = impl_errors::method_no_args::Serve;
+
+/// The same as the names given by `extern_impl_errors!` for `prefix = "a_"`.
+pub struct Prefixed;
+
+impl ExportedName for Prefixed {
+    const NAME: &'static str = "a_method";
+}
+
+type PrefixedMethod = impl_errors::method::ServeAs<Prefixed>;
+
+fn set_input(input: &[u8]) {
+    let mut context = VMContextBuilder::new().build();
+    context.input = input.to_vec();
+    testing_env!(context);
+}
+
+#[test]
+fn test_args_errors() {
+    // the error shows the path of the failing argument
+    let input = br#"{"receiver_id":"bob","amount":"1"}"#;
+    let err = <errors::method::Args<State> as FromBorrowedBytes<Json>>::from_borrowed_bytes(input)
+        .err()
+        .unwrap();
+    assert_eq!(err.path, "amount");
+    assert!(err.to_string().starts_with("amount: invalid type"));
+
+    assert_eq!(
+        <Method as Serve<Json, Json, impl_errors::method::_Diverger>>::METHOD_NAME,
+        "method"
+    );
+}
+
+#[test]
+fn test_args_errors_prefixed() {
+    // the error shows the name that the method is exported as, which
+    // is prefixed when `extern_impl_errors!` is given a prefix
+    assert_eq!(
+        <PrefixedMethod as Serve<Json, Json, impl_errors::method::_Diverger>>::METHOD_NAME,
+        "a_method"
+    );
+    assert_eq!(
+        <PrefixedMethod as contract_interface::MethodInfo<impl_errors::method::_Diverger>>::METHOD_NAME,
+        "a_method"
+    );
+}
+
+#[test]
+fn test_no_args() {
+    // methods without args accept an empty input, `{}` or `null`
+    for input in [&b""[..], b"{}", b" null "] {
+        set_input(input);
+        <MethodNoArgs as Serve<Json, Json, impl_errors::method_no_args::_Diverger>>::deserialize_args_from_input(input);
+    }
+
+    // any other input is read as their (empty) args, so that
+    // `deserialize_args_from_input` panics with `method_no_args: ..`
+    // on anything that isn't an object
+    type Args = errors::method_no_args::Args<State>;
+    assert!(<Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(br#"{"unknown":1}"#).is_ok());
+    for input in [&b"1"[..], b"\"x\"", b"[1]"] {
+        assert!(<Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(input).is_err());
+    }
+}
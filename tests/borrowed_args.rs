@@ -0,0 +1,56 @@
+//! The `Args` of methods whose `&str` and `&[u8]` args are held as a
+//! `Cow`, which borrows from the input when it can.
+
+use contract_interface::{contract, FromBorrowedBytes, Json};
+use std::borrow::Cow;
+
+#[contract]
+pub trait Borrowed {
+    fn method(&self, s: &str, b: &[u8]);
+}
+
+pub struct State;
+
+impl Borrowed for State {
+    fn method(&self, _s: &str, _b: &[u8]) {}
+}
+
+type Args<'a> = borrowed::method::Args<'a, State>;
+
+#[test]
+fn test_borrowed_str() {
+    let input = br#"{"s":"no escapes","b":[]}"#;
+    let args = <Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(input).unwrap();
+    assert!(matches!(args.s, Cow::Borrowed("no escapes")));
+
+    // the unescaped string is owned
+    let input = br#"{"s":"with \"escapes\" !","b":[]}"#;
+    let args = <Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(input).unwrap();
+    assert!(matches!(args.s, Cow::Owned(_)));
+    assert_eq!(args.s, r#"with "escapes" !"#);
+}
+
+#[test]
+fn test_json_bytes() {
+    // json bytes are an array of numbers, which are read into an owned value
+    let input = br#"{"s":"","b":[1,2,255]}"#;
+    let args = <Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(input).unwrap();
+    assert_eq!(&*args.b, &[1, 2, 255]);
+
+    let input = br#"{"s":"","b":[256]}"#;
+    let err = <Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(input)
+        .err()
+        .unwrap();
+    assert_eq!(err.path, "b[0]");
+
+    // clients send the owned values, in the same format
+    let args = borrowed::method::ClientArgs {
+        s: "with \"escapes\"".to_string(),
+        b: vec![1, 2, 255],
+        _phantom: Default::default(),
+    };
+    let bytes = near_sdk::serde_json::to_vec(&args).unwrap();
+    assert_eq!(bytes, br#"{"s":"with \"escapes\"","b":[1,2,255]}"#.to_vec());
+    let args = <Args as FromBorrowedBytes<Json>>::from_borrowed_bytes(&bytes).unwrap();
+    assert_eq!((&*args.s, &*args.b), ("with \"escapes\"", &[1, 2, 255][..]));
+}
@@ -0,0 +1,49 @@
+//! Methods that return a `Result`, with `#[contract(handle_result)]`,
+//! whose `Err` value is the message that the method aborts with.
+
+use contract_interface::contract;
+use contract_interface::handle_result::{ErrorCode, ErrorEncoding};
+use near_sdk::serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Error {
+    code: u32,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed with {}", self.code)
+    }
+}
+
+impl ErrorCode for Error {
+    fn error_code(&self) -> u32 {
+        self.code
+    }
+}
+
+#[contract]
+pub trait Fallible {
+    #[contract(handle_result)]
+    fn method_display(&self) -> Result<u8, Error>;
+
+    #[contract(handle_result(error = "json"))]
+    fn method_json(&self) -> Result<u8, Error>;
+
+    #[contract(handle_result(error = "code"))]
+    fn method_code(&self) -> Result<u8, Error>;
+}
+
+/// The message that a method with `Encoding` aborts with, for `Error { code: 7 }`.
+fn message<Encoding: ErrorEncoding<Error>>() -> String {
+    Encoding::encode(Error { code: 7 })
+}
+
+#[test]
+fn test_handle_result_messages() {
+    use fallible::{method_code, method_display, method_json};
+    assert_eq!(message::<method_display::ErrorEncoding>(), "failed with 7");
+    assert_eq!(message::<method_json::ErrorEncoding>(), r#"{"code":7}"#);
+    assert_eq!(message::<method_code::ErrorEncoding>(), "7");
+}
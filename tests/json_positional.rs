@@ -0,0 +1,60 @@
+//! The `Args` and `ClientArgs` of methods whose args are a json array.
+
+use contract_interface::{contract, FromBorrowedBytes, JsonPositional, ToBytes};
+
+#[contract]
+pub trait Positional {
+    #[contract(args_format = "json_positional")]
+    fn method(&self, a: u8, b: &str, c: Option<bool>);
+
+    #[contract(args_format = "json_positional")]
+    fn method_no_args(&self);
+}
+
+pub struct State;
+
+impl Positional for State {
+    fn method(&self, _a: u8, _b: &str, _c: Option<bool>) {}
+
+    fn method_no_args(&self) {}
+}
+
+#[test]
+fn test_json_positional_args() {
+    use positional::{method, method_no_args};
+    type Args<'a> = method::Args<'a, State>;
+
+    let input = br#"[1, "b", null]"#;
+    let args = <Args as FromBorrowedBytes<JsonPositional>>::from_borrowed_bytes(input).unwrap();
+    assert_eq!((args.a, &*args.b, args.c), (1, "b", None));
+
+    // the error shows the position of the failing argument
+    let input = br#"[1, 2, null]"#;
+    let err = <Args as FromBorrowedBytes<JsonPositional>>::from_borrowed_bytes(input)
+        .err()
+        .unwrap();
+    assert_eq!(err.path, "[1]");
+    let input = br#"[1, "b"]"#;
+    assert!(<Args as FromBorrowedBytes<JsonPositional>>::from_borrowed_bytes(input).is_err());
+
+    let args = method::ClientArgs {
+        a: 1,
+        b: "b".to_string(),
+        c: Some(true),
+        _phantom: Default::default(),
+    };
+    assert_eq!(
+        ToBytes::<JsonPositional>::to_bytes(&args).unwrap(),
+        br#"[1,"b",true]"#.to_vec()
+    );
+
+    let args = method_no_args::ClientArgs {
+        _phantom: Default::default(),
+    };
+    assert_eq!(
+        ToBytes::<JsonPositional>::to_bytes(&args).unwrap(),
+        b"[]".to_vec()
+    );
+    type NoArgs = method_no_args::Args<State>;
+    assert!(<NoArgs as FromBorrowedBytes<JsonPositional>>::from_borrowed_bytes(b"[]").is_ok());
+}
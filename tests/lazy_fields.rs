@@ -0,0 +1,53 @@
+//! A state that has each of it's fields stored under their own key,
+//! as generated by `#[contract(lazy_fields)]`.
+
+use contract_interface::{contract, Lazy, StateField, StateRead, StateWrite};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{env, test_utils::VMContextBuilder, testing_env};
+
+#[contract(lazy_fields)]
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct Contract {
+    count: u32,
+    names: Vec<String>,
+}
+
+type Count = contract::fields::count;
+type Names = contract::fields::names;
+
+fn contract() -> Contract {
+    Contract {
+        count: 1,
+        names: vec!["alice".to_string()],
+    }
+}
+
+#[test]
+fn test_lazy_keys() {
+    assert_eq!(<Count as StateField>::KEY, b"STATE.count");
+    assert_eq!(<Names as StateField>::KEY, b"STATE.names");
+}
+
+#[test]
+fn test_lazy() {
+    testing_env!(VMContextBuilder::new().build());
+    assert!(Lazy::<Contract>::state_read().is_none());
+
+    Lazy::from(contract()).state_write();
+
+    // the `STATE` key only marks the contract as initialized
+    assert_eq!(env::storage_read(b"STATE"), Some(vec![]));
+    assert_eq!(
+        env::storage_read(b"STATE.count"),
+        Some(1u32.try_to_vec().unwrap())
+    );
+    assert_eq!(
+        env::storage_read(b"STATE.names"),
+        Some(vec!["alice".to_string()].try_to_vec().unwrap())
+    );
+    assert_eq!(Lazy::<Contract>::state_read().unwrap().state, contract());
+
+    // all of the fields are required
+    env::storage_remove(b"STATE.names");
+    assert!(Lazy::<Contract>::state_read().is_none());
+}
@@ -0,0 +1,92 @@
+//! The owned-`self` methods, which give back the new state and a value
+//! that is returned to the caller, either as a pair or as a designated
+//! type that is split with `#[contract(split)]`.
+
+use contract_interface::{contract, SplitState};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{env, test_utils::VMContextBuilder, testing_env, ReturnData};
+
+/// The new state together with how many times it was counted.
+pub struct Counted<State> {
+    state: State,
+    times: u8,
+}
+
+impl<State> SplitState<State> for Counted<State> {
+    type Value = u8;
+    fn split_state(self) -> (State, u8) {
+        (self.state, self.times)
+    }
+}
+
+#[contract]
+pub trait Owned {
+    fn method_pair(self, amount: u8) -> (Self, u8)
+    where
+        Self: Sized;
+
+    #[contract(split)]
+    fn method_split(self, amount: u8) -> Counted<Self>
+    where
+        Self: Sized;
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Counter {
+    count: u8,
+}
+
+#[contract(mod = "impl_owned", trait = "owned")]
+impl Owned for Counter {
+    fn method_pair(mut self, amount: u8) -> (Self, u8) {
+        self.count += amount;
+        let count = self.count;
+        (self, count)
+    }
+
+    fn method_split(mut self, amount: u8) -> Counted<Self> {
+        self.count += amount;
+        Counted {
+            state: self,
+            times: amount,
+        }
+    }
+}
+
+pub mod api {
+    extern_impl_owned!(stored_type = super::Counter, impl_mod = super::impl_owned);
+}
+
+/// Calls the exported `method` on a stored `Counter`, and gives back
+/// the new count and the returned value.
+fn call(method: extern "C" fn(), count: u8, input: &[u8]) -> (u8, Vec<u8>) {
+    let mut context = VMContextBuilder::new().build();
+    context.input = input.to_vec();
+    testing_env!(context);
+    env::state_write(&Counter { count });
+
+    method();
+
+    let state = env::state_read::<Counter>().unwrap();
+    let ret = near_sdk::mock::with_mocked_blockchain(|b| b.outcome().return_data);
+    match ret {
+        ReturnData::Value(value) => (state.count, value),
+        other => panic!("unexpected return: {:?}", other),
+    }
+}
+
+#[test]
+fn test_owned_pair() {
+    assert_eq!(
+        call(api::method_pair, 1, br#"{"amount":2}"#),
+        (3, b"3".to_vec())
+    );
+}
+
+#[test]
+fn test_owned_split() {
+    assert_eq!(
+        call(api::method_split, 1, br#"{"amount":2}"#),
+        (3, b"2".to_vec())
+    );
+}
@@ -0,0 +1,125 @@
+//! An implementation that is served together with two supertraits,
+//! which share a supertrait of their own.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct State;
+
+#[contract]
+pub trait Base {
+    fn method_base(&self) -> u8;
+}
+
+#[contract]
+pub trait Left: Base {
+    fn method_left(&self) -> u8;
+}
+
+#[contract]
+pub trait Right: Base {
+    fn method_right(&self) -> u8;
+}
+
+#[contract]
+pub trait Top: Left + Right {
+    fn method_top(&self) -> u8;
+}
+
+#[contract(mod = "impl_base", trait = "base")]
+impl Base for State {
+    fn method_base(&self) -> u8 {
+        0
+    }
+}
+
+#[contract(
+    mod = "impl_left",
+    trait = "left",
+    supertrait(
+        extern_macro = "crate::macros::extern_impl_base",
+        impl_mod = "crate::impl_base"
+    )
+)]
+impl Left for State {
+    fn method_left(&self) -> u8 {
+        1
+    }
+}
+
+#[contract(
+    mod = "impl_right",
+    trait = "right",
+    supertrait(
+        extern_macro = "crate::macros::extern_impl_base",
+        impl_mod = "crate::impl_base"
+    )
+)]
+impl Right for State {
+    fn method_right(&self) -> u8 {
+        2
+    }
+}
+
+// `Base` is served by `Left`, so `Right` skips it
+#[contract(
+    mod = "impl_top",
+    trait = "top",
+    supertrait(
+        extern_macro = "crate::macros::extern_impl_left",
+        impl_mod = "crate::impl_left"
+    ),
+    supertrait(
+        extern_macro = "crate::macros::extern_impl_right",
+        impl_mod = "crate::impl_right",
+        skip_supertraits
+    )
+)]
+impl Top for State {
+    fn method_top(&self) -> u8 {
+        3
+    }
+}
+
+/// Exports each method once.
+pub mod api {
+    crate::macros::extern_impl_top!(stored_type = super::State, impl_mod = super::impl_top);
+}
+
+#[test]
+fn test_supertrait_diamond() {
+    let names = impl_top::methods()
+        .into_iter()
+        .map(|m| m.name)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["method_top", "method_left", "method_base", "method_right"]
+    );
+
+    // a supertrait's impl that is also given directly is listed once
+    let names =
+        contract_interface::contract_methods!(impl_mods = [impl_top, impl_right, impl_base])
+            .into_iter()
+            .map(|m| m.name)
+            .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["method_top", "method_left", "method_base", "method_right"]
+    );
+
+    let _top: extern "C" fn() = api::method_top;
+    let _left: extern "C" fn() = api::method_left;
+    let _right: extern "C" fn() = api::method_right;
+    let _base: extern "C" fn() = api::method_base;
+}
+
+pub mod macros {
+    pub use extern_impl_base;
+    pub use extern_impl_left;
+    pub use extern_impl_right;
+    pub use extern_impl_top;
+}
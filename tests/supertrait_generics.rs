@@ -0,0 +1,77 @@
+//! A generic implementation that is served together with it's
+//! supertrait, whose implementation has the same generics.
+
+#![allow(unused_variables)]
+
+use contract_interface::contract;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct State<T> {
+    value: T,
+}
+
+#[contract]
+pub trait Named<T> {
+    fn method_name(&self) -> String;
+}
+
+#[contract]
+pub trait Greeter<T>: Named<T> {
+    fn method_greet(&self) -> String;
+}
+
+#[contract(mod = "impl_named", trait = "named")]
+impl<T> Named<T> for State<T>
+where
+    T: BorshDeserialize + BorshSerialize + ToString,
+{
+    fn method_name(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+#[contract(
+    mod = "impl_greeter",
+    trait = "greeter",
+    supertrait(
+        extern_macro = "crate::macros::extern_impl_named",
+        impl_mod = "crate::impl_named"
+    )
+)]
+impl<T> Greeter<T> for State<T>
+where
+    T: BorshDeserialize + BorshSerialize + ToString,
+{
+    fn method_greet(&self) -> String {
+        format!("Hello, {}", self.method_name())
+    }
+}
+
+/// Exports `method_greet` and `method_name`, with the generics of
+/// both implementations set to `u8`.
+pub mod api {
+    crate::macros::extern_impl_greeter!(
+        stored_type = super::State<u8>,
+        impl_mod = super::impl_greeter,
+        <T> = u8
+    );
+}
+
+#[test]
+fn test_supertrait_generics() {
+    let names = impl_greeter::methods()
+        .into_iter()
+        .map(|m| m.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["method_greet", "method_name"]);
+
+    // both of the exported functions exist
+    let _greet: extern "C" fn() = api::method_greet;
+    let _name: extern "C" fn() = api::method_name;
+}
+
+pub mod macros {
+    pub use extern_impl_greeter;
+    pub use extern_impl_named;
+}
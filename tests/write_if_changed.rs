@@ -0,0 +1,80 @@
+//! A `&mut self` method that only writes the state back if it was
+//! changed, with `#[contract(write_if_changed)]`.
+
+use contract_interface::{contract, StateRead, StateWrite};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{env, test_utils::VMContextBuilder, testing_env};
+use std::cell::Cell;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Counter {
+    count: u32,
+}
+
+thread_local! {
+    static WRITES: Cell<u32> = Cell::new(0);
+}
+
+/// Stores the `Counter`, counting how many times it was written.
+pub struct Tracked {
+    counter: Counter,
+}
+
+impl StateRead for Tracked {
+    fn state_read() -> Option<Self> {
+        env::state_read().map(|counter| Self { counter })
+    }
+}
+
+impl StateWrite for Tracked {
+    fn state_write(&self) {
+        WRITES.with(|w| w.set(w.get() + 1));
+        env::state_write(&self.counter);
+    }
+}
+
+#[contract]
+pub trait Counting {
+    fn method_set(&mut self, count: u32);
+}
+
+#[contract(mod = "impl_counting", trait = "counting")]
+impl Counting for Counter {
+    #[contract(write_if_changed)]
+    fn method_set(&mut self, count: u32) {
+        self.count = count;
+    }
+}
+
+pub mod api {
+    extern_impl_counting!(
+        stored_type = super::Tracked,
+        state_access = state.counter,
+        impl_mod = super::impl_counting
+    );
+}
+
+/// Calls `method_set` on a stored `count`, and gives back the new
+/// count and how many times it was written.
+fn set(count: u32, input: &[u8]) -> (u32, u32) {
+    let mut context = VMContextBuilder::new().build();
+    context.input = input.to_vec();
+    testing_env!(context);
+    env::state_write(&Counter { count });
+    WRITES.with(|w| w.set(0));
+
+    api::method_set();
+
+    let counter = env::state_read::<Counter>().unwrap();
+    (counter.count, WRITES.with(|w| w.get()))
+}
+
+#[test]
+fn test_unchanged() {
+    assert_eq!(set(1, br#"{"count":1}"#), (1, 0));
+}
+
+#[test]
+fn test_changed() {
+    assert_eq!(set(1, br#"{"count":2}"#), (2, 1));
+}